@@ -0,0 +1,62 @@
+// Compares Day 1's sort-based top-N against the bounded heap, on a manifest far larger than the puzzle input.
+//
+//     cargo bench --bench day1_top_n [-- elves n]
+use aoc::year2022::day1::{stream_top_n, ElfManifest};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: usize = 5;
+
+fn main() {
+    // Cargo passes `--bench` through to harness-less benches, so only numeric arguments are read.
+    let args = std::env::args()
+        .skip(1)
+        .filter_map(|a| a.parse::<usize>().ok())
+        .collect::<Vec<usize>>();
+    let elves = args.first().copied().unwrap_or(1_000_000);
+    let n = args.get(1).copied().unwrap_or(3);
+
+    let input = generate_manifest(elves);
+    println!("{} elves, {} KiB of input, top {}", elves, input.len() / 1024, n);
+
+    let manifest = ElfManifest::new_from_str(&input).expect("Failed to parse manifest.");
+    let expected = manifest.get_top_n_calorie_sum(n) as u64;
+    assert_eq!(manifest.top_n(n).sum, expected);
+    assert_eq!(stream_top_n(input.as_bytes(), n).unwrap().sum, expected);
+
+    report("sort (parsed)", || manifest.get_top_n_calorie_sum(n) as u64);
+    report("heap (parsed)", || manifest.top_n(n).sum);
+    report("parse + sort", || {
+        ElfManifest::new_from_str(&input).unwrap().get_top_n_calorie_sum(n) as u64
+    });
+    report("stream + heap", || stream_top_n(input.as_bytes(), n).unwrap().sum);
+}
+
+fn report(name: &str, mut f: impl FnMut() -> u64) {
+    let mut times = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+    times.sort();
+    println!("{:<16} median {:>10.3?}  min {:>10.3?}", name, times[RUNS / 2], times[0]);
+}
+
+// Deterministic pseudo-random inventories of 1 to 15 items each.
+fn generate_manifest(elves: usize) -> String {
+    let mut state: u64 = 0x2022_1201;
+    let mut next = move |bound: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) % bound
+    };
+    let mut input = String::new();
+    for _ in 0..elves {
+        for _ in 0..=next(15) {
+            input += &format!("{}\n", 1000 + next(9000));
+        }
+        input.push('\n');
+    }
+    input
+}
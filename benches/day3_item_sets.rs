@@ -0,0 +1,157 @@
+// Compares Day 3's bitset item sets against the hash sets they replaced, on generated inputs far larger
+// than the puzzle's.
+//
+//     cargo bench --bench day3_item_sets [-- groups]
+use aoc::year2022::day3::RucksackCollection;
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: usize = 5;
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn main() {
+    // Cargo passes `--bench` through to harness-less benches, so only numeric arguments are read.
+    let groups = std::env::args()
+        .skip(1)
+        .find_map(|a| a.parse::<usize>().ok())
+        .unwrap_or(200_000);
+
+    let input = generate_rucksacks(groups);
+    println!("{} rucksacks, {} KiB of input", groups * 3, input.len() / 1024);
+
+    let bitset = RucksackCollection::new_from_str(&input).expect("Failed to parse rucksacks.");
+    let hashed = HashRucksacks::new_from_str(&input);
+    let expected = (bitset.score(), bitset.badge_score());
+    assert_eq!((hashed.score(), hashed.badge_score()), expected);
+
+    report("hash (parsed)", || hashed.score() + hashed.badge_score());
+    report("bitset (parsed)", || bitset.score() + bitset.badge_score());
+    report("hash + parse", || {
+        let hashed = HashRucksacks::new_from_str(&input);
+        hashed.score() + hashed.badge_score()
+    });
+    report("bitset + parse", || {
+        let bitset = RucksackCollection::new_from_str(&input).unwrap();
+        bitset.score() + bitset.badge_score()
+    });
+}
+
+fn report(name: &str, mut f: impl FnMut() -> u32) {
+    let mut times = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+    times.sort();
+    println!("{:<16} median {:>10.3?}  min {:>10.3?}", name, times[RUNS / 2], times[0]);
+}
+
+// Deterministic pseudo-random groups of three rucksacks, each with exactly one item in both of its
+// compartments and exactly one badge shared by the group, as the puzzle promises.
+fn generate_rucksacks(groups: usize) -> String {
+    let mut state: u64 = 0x2022_1203;
+    let mut next = move |bound: usize| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((state >> 33) % bound as u64) as usize
+    };
+    let mut input = String::new();
+    for _ in 0..groups {
+        let mut items = ITEMS.to_vec();
+        for i in (1..items.len()).rev() {
+            items.swap(i, next(i + 1));
+        }
+        let badge = items[0];
+        // Every rucksack draws from its own 17 items, so only the badge is common to the group.
+        for pool in items[1..].chunks(17) {
+            let (shared, rest) = (pool[0], &pool[1..]);
+            let (left_only, right_only) = rest.split_at(rest.len() / 2);
+            let len = 8 + next(8);
+            let mut left = vec![shared, badge];
+            let mut right = vec![shared];
+            while left.len() < len {
+                left.push(left_only[next(left_only.len())]);
+            }
+            while right.len() < len {
+                right.push(right_only[next(right_only.len())]);
+            }
+            for half in [&mut left, &mut right] {
+                for i in (1..half.len()).rev() {
+                    half.swap(i, next(i + 1));
+                }
+            }
+            input.extend(left.iter().chain(right.iter()).map(|b| *b as char));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+// Day 3 as it was before item sets: each compartment kept its string and a `HashSet<char>`.
+struct HashRucksacks {
+    rucksacks: Vec<HashRucksack>,
+}
+
+struct HashRucksack {
+    left: (String, HashSet<char>),
+    right: (String, HashSet<char>),
+}
+
+impl HashRucksacks {
+    fn new_from_str(input: &str) -> Self {
+        let compartment = |items: &str| (items.to_string(), items.chars().collect::<HashSet<char>>());
+        let rucksacks = input
+            .lines()
+            .map(|line| {
+                let (left, right) = line.split_at(line.len() / 2);
+                HashRucksack {
+                    left: compartment(left),
+                    right: compartment(right),
+                }
+            })
+            .collect();
+        Self { rucksacks }
+    }
+
+    fn score(&self) -> u32 {
+        self.rucksacks
+            .iter()
+            .map(|r| r.right.0.chars().find(|c| r.left.1.contains(c)).map(char_score).unwrap_or(0))
+            .sum()
+    }
+
+    fn badge_score(&self) -> u32 {
+        self.rucksacks
+            .chunks(3)
+            .map(|group| {
+                let mut candidates = HashSet::new();
+                for c in group[1].chars() {
+                    if group[0].has_char(&c) {
+                        candidates.insert(c);
+                    }
+                }
+                group[2].chars().into_iter().find(|c| candidates.contains(c)).map(char_score).unwrap()
+            })
+            .sum()
+    }
+}
+
+impl HashRucksack {
+    fn chars(&self) -> Vec<char> {
+        self.left.0.chars().chain(self.right.0.chars()).collect()
+    }
+
+    fn has_char(&self, c: &char) -> bool {
+        self.left.1.contains(c) || self.right.1.contains(c)
+    }
+}
+
+fn char_score(c: char) -> u32 {
+    if c.is_uppercase() {
+        c as u32 - 65 + 26 + 1
+    } else {
+        c as u32 - 97 + 1
+    }
+}
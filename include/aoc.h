@@ -0,0 +1,66 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A null pointer or input that isn't valid UTF-8 was passed in.
+ */
+#define AOC_ERROR_INVALID_ARGUMENT -1
+
+/**
+ * The day or part doesn't exist, or the input couldn't be parsed.
+ */
+#define AOC_ERROR_SOLVE -2
+
+/**
+ * The solver panicked, usually because of malformed input.
+ */
+#define AOC_ERROR_PANIC -3
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Solves one part of a 2022 puzzle from `input_len` bytes of UTF-8 input text.
+ *
+ * On success the answer is written to `out_buf` as a NUL-terminated string and its length
+ * in bytes (without the NUL) is returned. As with `snprintf`, a return value of `out_len` or
+ * more means the answer was truncated, and the call can be retried with a larger buffer.
+ *
+ * On failure one of the negative `AOC_ERROR_*` codes is returned and, if there is room, an
+ * error message is written to `out_buf` instead.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes and `out_buf` to `out_len` writable bytes.
+ * `out_buf` may be null if `out_len` is 0.
+ */
+int32_t aoc_solve(uint32_t day,
+                  uint32_t part,
+                  const char *input,
+                  size_t input_len,
+                  char *out_buf,
+                  size_t out_len);
+
+/**
+ * Writes up to `out_len` of the available days into `out` and returns how many there are in total.
+ *
+ * # Safety
+ *
+ * `out` must point to `out_len` writable `uint32_t`s. It may be null if `out_len` is 0.
+ */
+size_t aoc_days(uint32_t *out, size_t out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
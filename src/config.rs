@@ -0,0 +1,243 @@
+use aoc::trace::{self, Level};
+use aoc::{custom_error, read_input_to_str, AOCSolution, SResult};
+use std::collections::HashMap;
+
+// Parameter overrides keyed by year and day, collected from a config file and the command line.
+//
+// Config files use one section per day:
+//
+//     [2022.day7]
+//     total_space = 70000000
+//
+// On the command line, `--param 2022.day7.total_space=70000000` sets the same value.
+// The year may be left off (`day7`), in which case it defaults to 2022.
+#[derive(Debug, Default)]
+pub struct RunnerConfig {
+    overrides: HashMap<(u32, u32), Vec<(String, String)>>,
+    pub list_params: bool,
+    pub report: bool,
+    #[cfg(feature = "profile-memory")]
+    pub profile_memory: bool,
+    // Set by the `serve` subcommand, with the address to listen on.
+    pub serve: Option<String>,
+    traces: Vec<((u32, u32), Level)>,
+    trace_file: Option<String>,
+}
+
+impl RunnerConfig {
+    pub fn from_args<I: Iterator<Item = String>>(mut args: I) -> SResult<Self> {
+        let mut config = Self::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--config" | "-c" => {
+                    let path = args.next().ok_or(ConfigError("Missing path after --config."))?;
+                    config.load_file(&path)?;
+                }
+                "--param" | "-p" => {
+                    let param = args.next().ok_or(ConfigError("Missing value after --param."))?;
+                    config.add_override(&param)?;
+                }
+                "--list-params" => config.list_params = true,
+                "--report" => config.report = true,
+                #[cfg(feature = "profile-memory")]
+                "--profile-memory" => config.profile_memory = true,
+                #[cfg(not(feature = "profile-memory"))]
+                "--profile-memory" => {
+                    return Err(Box::new(ConfigError("--profile-memory needs the profile-memory feature.")));
+                }
+                "serve" => {
+                    config.serve.get_or_insert_with(|| crate::serve::DEFAULT_ADDRESS.to_string());
+                }
+                "--trace" | "-t" => {
+                    let target = args.next().ok_or(ConfigError("Missing day after --trace."))?;
+                    config.add_trace(&target)?;
+                }
+                "--trace-file" => {
+                    let path = args.next().ok_or(ConfigError("Missing path after --trace-file."))?;
+                    config.trace_file = Some(path);
+                }
+                "--addr" => {
+                    let addr = args.next().ok_or(ConfigError("Missing address after --addr."))?;
+                    if config.serve.is_none() {
+                        return Err(Box::new(ConfigError("--addr is only valid after serve.")));
+                    }
+                    config.serve = Some(addr);
+                }
+                _ => return Err(Box::new(ConfigError("Unknown argument."))),
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn load_file(&mut self, path: &str) -> SResult<()> {
+        let contents = read_input_to_str(path, true)?;
+        self.load_str(&contents)
+    }
+
+    pub fn load_str(&mut self, contents: &str) -> SResult<()> {
+        let mut target = None;
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                target = Some(parse_target(section)?);
+                continue;
+            }
+            let target = target.ok_or(ConfigError("Parameter given outside of a [dayN] section."))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(ConfigError("Invalid config line, expected key = value."))?;
+            self.push(target, key.trim(), value.trim());
+        }
+        Ok(())
+    }
+
+    // Accepts `YYYY.dayN.key=value` or `dayN.key=value`.
+    pub fn add_override(&mut self, param: &str) -> SResult<()> {
+        let (target, value) = param
+            .split_once('=')
+            .ok_or(ConfigError("Invalid parameter, expected dayN.key=value."))?;
+        let (target, key) = target
+            .rsplit_once('.')
+            .ok_or(ConfigError("Invalid parameter, expected dayN.key=value."))?;
+        self.push(parse_target(target)?, key.trim(), value.trim());
+        Ok(())
+    }
+
+    // Accepts `YYYY.dayN` or `dayN`, optionally followed by `=level` (defaults to debug).
+    pub fn add_trace(&mut self, target: &str) -> SResult<()> {
+        let (target, level) = match target.split_once('=') {
+            Some((target, level)) => (target, level.parse::<Level>()?),
+            None => (target, Level::Debug),
+        };
+        self.traces.push((parse_target(target)?, level));
+        Ok(())
+    }
+
+    // Turns on the requested tracing, writing JSONL to the trace file if one was given.
+    pub fn install_tracing(&self) -> SResult<()> {
+        if !trace::COMPILED && (!self.traces.is_empty() || self.trace_file.is_some()) {
+            return Err(Box::new(ConfigError("Tracing needs the trace feature.")));
+        }
+        if let Some(path) = &self.trace_file {
+            let file = std::fs::File::create(path)?;
+            trace::set_jsonl_output(Box::new(std::io::LineWriter::new(file)));
+        }
+        for ((year, day), level) in self.traces.iter() {
+            trace::enable(*year, *day, *level);
+        }
+        Ok(())
+    }
+
+    pub fn apply(&self, year: u32, day: u32, solution: &mut dyn AOCSolution) -> SResult<()> {
+        let overrides = match self.overrides.get(&(year, day)) {
+            Some(o) => o,
+            None => return Ok(()),
+        };
+        let params = solution
+            .params_mut()
+            .ok_or(ConfigError("Parameters given for a day without parameters."))?;
+        for (key, value) in overrides.iter() {
+            params.set(key, value)?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, target: (u32, u32), key: &str, value: &str) {
+        self.overrides
+            .entry(target)
+            .or_default()
+            .push((key.to_string(), value.to_string()));
+    }
+}
+
+const DEFAULT_YEAR: u32 = 2022;
+
+// Parses `YYYY.dayN` or `dayN` into a (year, day) pair.
+fn parse_target(s: &str) -> Result<(u32, u32), ConfigError> {
+    let (year, day) = match s.trim().split_once('.') {
+        Some((year, day)) => (
+            year.parse::<u32>().map_err(|_| ConfigError("Invalid year, expected YYYY.dayN."))?,
+            day,
+        ),
+        None => (DEFAULT_YEAR, s.trim()),
+    };
+    let day = day
+        .strip_prefix("day")
+        .and_then(|d| d.parse::<u32>().ok())
+        .ok_or(ConfigError("Invalid day, expected dayN."))?;
+    Ok((year, day))
+}
+
+custom_error!(ConfigError);
+
+#[cfg(all(test, feature = "day7"))]
+mod test {
+    use super::*;
+    use aoc::year2022::day7::FileSystem;
+
+    #[test]
+    fn overrides_from_file_and_args() {
+        let mut config = RunnerConfig::default();
+        config
+            .load_str(
+                "
+                # Shrink the disk
+                [2022.day7]
+                total_space = 50000000
+                ",
+            )
+            .expect("Failed to parse config");
+        config.add_override("day7.required_space=1").expect("Failed to parse override");
+
+        let mut fs = FileSystem::new_from_str("$ cd /\n$ ls\n100 a").expect("Failed to parse");
+        config.apply(2022, 7, &mut fs).expect("Failed to apply");
+        let entries = fs.params_mut().unwrap().entries();
+        assert!(entries.contains(&("total_space", "50000000".to_string())));
+        assert!(entries.contains(&("required_space", "1".to_string())));
+    }
+
+    #[test]
+    fn rejects_unknown_param() {
+        let mut config = RunnerConfig::default();
+        config.add_override("day7.nonsense=1").expect("Failed to parse override");
+        let mut fs = FileSystem::new_from_str("$ cd /").expect("Failed to parse");
+        assert!(config.apply(2022, 7, &mut fs).is_err());
+    }
+
+    #[test]
+    fn overrides_are_scoped_to_year() {
+        let mut config = RunnerConfig::default();
+        config.add_override("2021.day7.nonsense=1").expect("Failed to parse override");
+        let mut fs = FileSystem::new_from_str("$ cd /").expect("Failed to parse");
+        assert!(config.apply(2022, 7, &mut fs).is_ok());
+        assert!(config.apply(2021, 7, &mut fs).is_err());
+    }
+
+    #[test]
+    fn parses_trace_targets() {
+        let config = RunnerConfig::from_args(
+            ["--trace", "day7", "-t", "2022.day11=trace", "--trace-file", "out.jsonl"]
+                .iter()
+                .map(|s| s.to_string()),
+        )
+        .expect("Failed to parse args");
+        assert_eq!(config.traces, vec![((2022, 7), Level::Debug), ((2022, 11), Level::Trace)]);
+        assert_eq!(config.trace_file.as_deref(), Some("out.jsonl"));
+        assert!(RunnerConfig::from_args(["--trace", "day7=loud"].iter().map(|s| s.to_string())).is_err());
+        // A year no solution uses, so hooks in tests running alongside stay quiet.
+        let config = RunnerConfig::from_args(["--trace", "1999.day3"].iter().map(|s| s.to_string())).unwrap();
+        assert_eq!(config.install_tracing().is_ok(), trace::COMPILED);
+    }
+
+    #[test]
+    fn addr_needs_serve() {
+        let args = |args: &[&str]| RunnerConfig::from_args(args.iter().map(|s| s.to_string()));
+        let config = args(&["serve", "--addr", "127.0.0.1:9000"]).expect("Failed to parse args");
+        assert_eq!(config.serve.as_deref(), Some("127.0.0.1:9000"));
+        assert!(args(&["--addr", "127.0.0.1:9000"]).is_err());
+        assert!(args(&["--addr", "127.0.0.1:9000", "serve"]).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+// C ABI over the solvers, for calling them from other languages through the cdylib.
+// The matching header is include/aoc.h, generated with cbindgen (see the README).
+use std::os::raw::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::slice;
+
+// Like the wasm build, the C API only covers the 2022 calendar.
+const YEAR: u32 = 2022;
+
+/// A null pointer or input that isn't valid UTF-8 was passed in.
+pub const AOC_ERROR_INVALID_ARGUMENT: i32 = -1;
+/// The day or part doesn't exist, or the input couldn't be parsed.
+pub const AOC_ERROR_SOLVE: i32 = -2;
+/// The solver panicked, usually because of malformed input.
+pub const AOC_ERROR_PANIC: i32 = -3;
+
+/// Solves one part of a 2022 puzzle from `input_len` bytes of UTF-8 input text.
+///
+/// On success the answer is written to `out_buf` as a NUL-terminated string and its length
+/// in bytes (without the NUL) is returned. As with `snprintf`, a return value of `out_len` or
+/// more means the answer was truncated, and the call can be retried with a larger buffer.
+///
+/// On failure one of the negative `AOC_ERROR_*` codes is returned and, if there is room, an
+/// error message is written to `out_buf` instead.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes and `out_buf` to `out_len` writable bytes.
+/// `out_buf` may be null if `out_len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input: *const c_char,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    if input.is_null() || (out_buf.is_null() && out_len > 0) {
+        return AOC_ERROR_INVALID_ARGUMENT;
+    }
+    let out = if out_len > 0 {
+        slice::from_raw_parts_mut(out_buf as *mut u8, out_len)
+    } else {
+        &mut []
+    };
+    let input = match std::str::from_utf8(slice::from_raw_parts(input as *const u8, input_len)) {
+        Ok(input) => input,
+        Err(_) => {
+            write_c_str(out, "Input is not valid UTF-8.");
+            return AOC_ERROR_INVALID_ARGUMENT;
+        }
+    };
+
+    // Unwinding across the C boundary would abort the caller, so panics are caught here.
+    match catch_unwind(AssertUnwindSafe(|| crate::solve(YEAR, day, part, input))) {
+        Ok(Ok(answer)) => {
+            write_c_str(out, &answer);
+            answer.len().try_into().unwrap_or(i32::MAX)
+        }
+        Ok(Err(e)) => {
+            write_c_str(out, &e.to_string());
+            AOC_ERROR_SOLVE
+        }
+        Err(_) => {
+            write_c_str(out, "The solver panicked on this input.");
+            AOC_ERROR_PANIC
+        }
+    }
+}
+
+/// Writes up to `out_len` of the available days into `out` and returns how many there are in total.
+///
+/// # Safety
+///
+/// `out` must point to `out_len` writable `uint32_t`s. It may be null if `out_len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_days(out: *mut u32, out_len: usize) -> usize {
+    let days = crate::registry()
+        .iter()
+        .filter(|s| s.year == YEAR)
+        .map(|s| s.day)
+        .collect::<Vec<u32>>();
+    if !out.is_null() {
+        let out = slice::from_raw_parts_mut(out, out_len);
+        for (slot, day) in out.iter_mut().zip(days.iter()) {
+            *slot = *day;
+        }
+    }
+    days.len()
+}
+
+// Copies as much of `s` as fits and NUL-terminates it.
+fn write_c_str(out: &mut [u8], s: &str) {
+    if out.is_empty() {
+        return;
+    }
+    let len = s.len().min(out.len() - 1);
+    out[..len].copy_from_slice(&s.as_bytes()[..len]);
+    out[len] = 0;
+}
+
+#[cfg(all(test, feature = "day1"))]
+mod test {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn call(day: u32, part: u32, input: &str, out_len: usize) -> (i32, String) {
+        let mut out = vec![0 as c_char; out_len.max(1)];
+        let written = unsafe { aoc_solve(day, part, input.as_ptr() as *const c_char, input.len(), out.as_mut_ptr(), out_len) };
+        let text = unsafe { CStr::from_ptr(out.as_ptr()) }.to_string_lossy().into_owned();
+        (written, text)
+    }
+
+    #[test]
+    fn solves_through_c_abi() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        assert_eq!(call(1, 1, input, 64), (5, "24000".to_string()));
+        assert_eq!(call(1, 2, input, 64), (5, "45000".to_string()));
+        // Truncated like snprintf, reporting the full length.
+        assert_eq!(call(1, 1, input, 3), (5, "24".to_string()));
+
+        assert_eq!(call(1, 3, input, 64).0, AOC_ERROR_SOLVE);
+        assert_eq!(call(99, 1, input, 64).0, AOC_ERROR_SOLVE);
+        assert_eq!(call(1, 1, "not a number", 64).0, AOC_ERROR_SOLVE);
+
+        let count = unsafe { aoc_days(std::ptr::null_mut(), 0) };
+        let mut days = vec![0; count];
+        unsafe { aoc_days(days.as_mut_ptr(), days.len()) };
+        assert!(days.contains(&1));
+    }
+}
@@ -0,0 +1,51 @@
+#[macro_use]
+pub mod util;
+#[macro_use]
+pub mod trace;
+pub mod year2022;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod ffi;
+// Test support, public so integration tests under tests/ can use it through the `snapshot` feature.
+#[cfg(any(test, feature = "snapshot"))]
+pub mod snapshot;
+
+pub use util::*;
+
+// Every registered solution, keyed by year and day.
+pub fn registry() -> Vec<RegisteredSolution> {
+    let mut solutions = Vec::new();
+    solutions.extend(year2022::registry());
+    solutions
+}
+
+// Solves one part of a puzzle from its input text rather than from the input directory.
+pub fn solve(year: u32, day: u32, part: u32, input: &str) -> SResult<String> {
+    let entry = registry()
+        .into_iter()
+        .find(|s| s.year == year && s.day == day)
+        .ok_or(SolveError("No solution registered for that year and day."))?;
+    let mut solution = (entry.parse)(input)?;
+    match part {
+        1 => solution.try_part_1(),
+        2 => solution.try_part_2(),
+        _ => Err(Box::new(SolveError("Invalid part, expected 1 or 2."))),
+    }
+}
+
+custom_error!(SolveError);
+
+#[cfg(all(test, feature = "day1"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solves_from_text() {
+        let input = "1000\n2000\n\n4000\n\n5000\n6000";
+        assert_eq!(solve(2022, 1, 1, input).unwrap(), "11000");
+        assert_eq!(solve(2022, 1, 2, input).unwrap(), "18000");
+        assert!(solve(2022, 1, 3, input).is_err());
+        assert!(solve(1999, 1, 1, input).is_err());
+    }
+}
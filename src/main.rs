@@ -1,50 +1,104 @@
-#[macro_use]
-mod util;
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-
-pub use util::*;
+mod config;
+#[cfg(feature = "profile-memory")]
+mod profiling;
+mod serve;
+
+use aoc::{registry, AOCSolution, SResult};
+use config::RunnerConfig;
+use serve::SolveServer;
+
+#[cfg(feature = "profile-memory")]
+#[global_allocator]
+static GLOBAL: profiling::CountingAllocator = profiling::CountingAllocator;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let solutions: Vec<Box<dyn AOCSolution>> = vec![
-        day1::solution()?,
-        day2::solution()?,
-        day3::solution()?,
-        day4::solution()?,
-        day5::solution()?,
-        day6::solution()?,
-        day7::solution()?,
-        day8::solution()?,
-        day9::solution()?,
-        day10::solution()?,
-        day11::solution()?,
-        day12::solution()?,
-        day13::solution()?,
-        day14::solution()?,
-        day15::solution()?,
-    ];
-    print_solutions(solutions);
+    let mut config = RunnerConfig::from_args(std::env::args().skip(1))?;
+    config.install_tracing()?;
+    if let Some(address) = config.serve.take() {
+        let server = SolveServer::bind(&address, config)?;
+        println!("Listening on http://{}", server.local_addr()?);
+        return server.run();
+    }
+    #[cfg(feature = "profile-memory")]
+    if config.profile_memory {
+        return profile_solutions(&config);
+    }
+    let mut solutions = Vec::new();
+    for entry in registry() {
+        let (year, day) = (entry.year, entry.day);
+        match (entry.load)() {
+            Ok(mut sol) => {
+                config.apply(year, day, sol.as_mut())?;
+                solutions.push((year, day, sol));
+            }
+            // Keep going so one missing input doesn't hide every other day's answers.
+            Err(e) => eprintln!("{} Day {}: unable to load solution: {}", year, day, e),
+        }
+    }
+    if config.list_params {
+        print_params(solutions);
+    } else if config.report {
+        print_reports(solutions);
+    } else {
+        print_solutions(solutions);
+    }
     Ok(())
 }
 
-fn print_solutions<'a>(mut solns: Vec<Box<dyn AOCSolution + 'a>>) {
-    for (day, sol) in solns.iter_mut().enumerate() {
-        println!("Day {}:", day + 1); // Zero-indexed
-        println!("Part 1: {}", sol.part_1());
-        println!("Part 2: {}", sol.part_2());
+fn print_params(mut solns: Vec<(u32, u32, Box<dyn AOCSolution>)>) {
+    for (year, day, sol) in solns.iter_mut() {
+        if let Some(params) = sol.params_mut() {
+            println!("[{}.day{}]", year, day);
+            for (key, value) in params.entries() {
+                println!("{} = {}", key, value);
+            }
+            println!();
+        }
+    }
+}
+
+fn print_reports(mut solns: Vec<(u32, u32, Box<dyn AOCSolution>)>) {
+    for (year, day, sol) in solns.iter_mut() {
+        if let Some(report) = sol.report() {
+            println!("{} Day {} report:", year, day);
+            println!("{}", report);
+        }
+    }
+}
+
+fn print_solutions(mut solns: Vec<(u32, u32, Box<dyn AOCSolution>)>) {
+    for (year, day, sol) in solns.iter_mut() {
+        println!("{} Day {}:", year, day);
+        println!("Part 1: {}", answer(sol.try_part_1()));
+        println!("Part 2: {}", answer(sol.try_part_2()));
         println!();
     }
 }
+
+fn answer(result: SResult<String>) -> String {
+    result.unwrap_or_else(|e| format!("Error: {}", e))
+}
+
+// Loads and solves one day at a time so each stage's allocations are measured in isolation.
+#[cfg(feature = "profile-memory")]
+fn profile_solutions(config: &RunnerConfig) -> SResult<()> {
+    profiling::print_header();
+    for entry in registry() {
+        let (year, day) = (entry.year, entry.day);
+        let (sol, load_stats) = profiling::measure(entry.load);
+        let mut sol = match sol {
+            Ok(sol) => sol,
+            Err(e) => {
+                eprintln!("{} Day {}: unable to load solution: {}", year, day, e);
+                continue;
+            }
+        };
+        config.apply(year, day, sol.as_mut())?;
+        profiling::print_row(year, day, "load", &load_stats);
+        let (_, part_1_stats) = profiling::measure(|| sol.part_1());
+        profiling::print_row(year, day, "part 1", &part_1_stats);
+        let (_, part_2_stats) = profiling::measure(|| sol.part_2());
+        profiling::print_row(year, day, "part 2", &part_2_stats);
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+// Wraps the system allocator and keeps running totals of allocation activity.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        // A resize isn't a new allocation: only growth counts towards the bytes allocated.
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                record_growth(new_size - layout.size());
+            } else {
+                CURRENT_BYTES.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    record_growth(size);
+}
+
+fn record_growth(size: usize) {
+    BYTES_ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    // Highest live heap size reached during the measurement, above what was live when it started.
+    pub peak_bytes: usize,
+}
+
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    let start_current = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(start_current, Ordering::Relaxed);
+    let start_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start_bytes = BYTES_ALLOCATED.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    let result = f();
+
+    let elapsed = start.elapsed();
+    let measurement = Measurement {
+        elapsed,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - start_allocations,
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed) - start_bytes,
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(start_current),
+    };
+    (result, measurement)
+}
+
+pub fn print_header() {
+    println!(
+        "{:>4} {:>4}  {:<6} {:>12} {:>12} {:>14} {:>14}",
+        "Year", "Day", "Stage", "Time", "Allocs", "Bytes", "Peak"
+    );
+}
+
+pub fn print_row(year: u32, day: u32, stage: &str, m: &Measurement) {
+    println!(
+        "{:>4} {:>4}  {:<6} {:>12} {:>12} {:>14} {:>14}",
+        year,
+        day,
+        stage,
+        format!("{:.3?}", m.elapsed),
+        m.allocations,
+        format_bytes(m.bytes_allocated),
+        format_bytes(m.peak_bytes)
+    );
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+// Tests that read the counters are in tests/profiling.rs, a binary of their own, since the counters are
+// process-wide and the runner's other tests allocate on threads alongside them.
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(4096), "4.0 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+    }
+}
@@ -0,0 +1,316 @@
+use crate::config::RunnerConfig;
+use aoc::{custom_error, json_string, registry, SResult};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:8022";
+// Years can be left out of request paths, in which case this one is assumed.
+const DEFAULT_YEAR: u32 = 2022;
+// Larger request bodies are refused with 413 rather than buffered.
+const MAX_BODY: usize = 8 * 1024 * 1024;
+// Longer request or header lines, and more headers than this, are refused rather than buffered.
+const MAX_LINE: usize = 8 * 1024;
+const MAX_HEADERS: usize = 100;
+// How long a client may stall mid-request or mid-response before its connection is dropped.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+// A minimal HTTP/1.1 server answering one request per connection, each on its own thread:
+//
+//     GET  /days                       registered solutions
+//     POST /solve/{day}/{part}         solve with the request body as puzzle input
+//     POST /solve/{year}/{day}/{part}
+pub struct SolveServer {
+    listener: TcpListener,
+    config: RunnerConfig,
+}
+
+impl SolveServer {
+    pub fn bind(address: &str, config: RunnerConfig) -> SResult<Self> {
+        let listener = TcpListener::bind(address)?;
+        Ok(Self { listener, config })
+    }
+
+    pub fn local_addr(&self) -> SResult<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    pub fn run(&self) -> SResult<()> {
+        std::thread::scope(|scope| {
+            for stream in self.listener.incoming() {
+                // A bad or slow connection shouldn't take the whole server down, or hold up the others.
+                match stream {
+                    Ok(stream) => {
+                        scope.spawn(move || {
+                            if let Err(e) = self.handle(stream) {
+                                eprintln!("serve: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => eprintln!("serve: {}", e),
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn handle(&self, mut stream: TcpStream) -> SResult<()> {
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        let response = match Request::read_from(&mut stream)? {
+            Ok(request) => self.route(&request),
+            Err(rejection) => rejection,
+        };
+        response.write_to(&mut stream)
+    }
+
+    fn route(&self, request: &Request) -> Response {
+        let segments = request
+            .path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<&str>>();
+        match (request.method.as_str(), segments.as_slice()) {
+            ("GET", ["days"]) => self.list_days(),
+            ("POST", ["solve", day, part]) => self.solve(&DEFAULT_YEAR.to_string(), day, part, &request.body),
+            ("POST", ["solve", year, day, part]) => self.solve(year, day, part, &request.body),
+            (_, ["days"]) | (_, ["solve", ..]) => Response::error(405, "Method not allowed"),
+            _ => Response::error(404, "Not found"),
+        }
+    }
+
+    fn list_days(&self) -> Response {
+        let days = registry()
+            .iter()
+            .map(|s| format!("{{\"year\":{},\"day\":{}}}", s.year, s.day))
+            .collect::<Vec<String>>();
+        Response::ok(format!("[{}]", days.join(",")))
+    }
+
+    fn solve(&self, year: &str, day: &str, part: &str, input: &str) -> Response {
+        let (year, day, part) = match (year.parse::<u32>(), day.parse::<u32>(), part.parse::<u32>()) {
+            (Ok(year), Ok(day), Ok(part)) => (year, day, part),
+            _ => return Response::error(400, "Year, day and part must be numbers"),
+        };
+        if part != 1 && part != 2 {
+            return Response::error(400, "Part must be 1 or 2");
+        }
+        let entry = match registry().into_iter().find(|s| s.year == year && s.day == day) {
+            Some(entry) => entry,
+            None => return Response::error(404, "No solution registered for that year and day"),
+        };
+
+        let start = Instant::now();
+        // Solutions unwrap freely on malformed input, so contain panics to this request.
+        let result = catch_unwind(AssertUnwindSafe(|| -> SResult<String> {
+            let mut solution = (entry.parse)(input)?;
+            self.config.apply(year, day, solution.as_mut())?;
+            if part == 1 { solution.try_part_1() } else { solution.try_part_2() }
+        }));
+        let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+        match result {
+            Ok(Ok(answer)) => Response::ok(format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ms\":{:.3}}}",
+                year,
+                day,
+                part,
+                json_string(&answer),
+                elapsed_ms
+            )),
+            Ok(Err(e)) => Response::error(422, &e.to_string()),
+            Err(_) => Response::error(500, "Solver panicked on this input"),
+        }
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+impl Request {
+    // Requests the server refuses outright come back as the response to send instead.
+    fn read_from(stream: &mut TcpStream) -> SResult<Result<Self, Response>> {
+        let mut reader = BufReader::new(stream);
+        let Some(request_line) = read_capped_line(&mut reader)? else {
+            return Ok(Err(Response::error(400, "Request line too long")));
+        };
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().ok_or(ServeError("Empty request"))?.to_string();
+        let path = parts.next().ok_or(ServeError("Missing request path"))?.to_string();
+
+        let mut content_length = 0;
+        let mut headers = 0;
+        loop {
+            let Some(header) = read_capped_line(&mut reader)? else {
+                return Ok(Err(Response::error(431, "Request header fields too large")));
+            };
+            if header.trim().is_empty() {
+                break;
+            }
+            headers += 1;
+            if headers > MAX_HEADERS {
+                return Ok(Err(Response::error(431, "Request header fields too large")));
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value
+                        .trim()
+                        .parse::<usize>()
+                        .map_err(|_| ServeError("Invalid Content-Length"))?;
+                }
+            }
+        }
+
+        if content_length > MAX_BODY {
+            return Ok(Err(Response::error(413, "Request body too large")));
+        }
+        let mut body = Vec::new();
+        reader.take(content_length as u64).read_to_end(&mut body)?;
+        if body.len() != content_length {
+            return Err(Box::new(ServeError("Request body shorter than Content-Length")));
+        }
+        let body = String::from_utf8(body).map_err(|_| ServeError("Request body is not UTF-8"))?;
+        Ok(Ok(Self { method, path, body }))
+    }
+}
+
+// One line, or whatever is left at the end of the stream, unless it runs past `MAX_LINE` bytes.
+fn read_capped_line<R: BufRead>(reader: &mut R) -> SResult<Option<String>> {
+    let mut line = String::new();
+    reader.take(MAX_LINE as u64).read_line(&mut line)?;
+    Ok((line.len() < MAX_LINE || line.ends_with('\n')).then_some(line))
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            422 => "Unprocessable Entity",
+            _ => "Internal Server Error",
+        }
+    }
+
+    fn write_to(&self, stream: &mut TcpStream) -> SResult<()> {
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )?;
+        stream.flush()?;
+        Ok(())
+    }
+}
+
+custom_error!(ServeError);
+
+#[cfg(all(test, feature = "day1"))]
+mod test {
+    use super::*;
+
+    fn request(addr: SocketAddr, raw: &str) -> String {
+        let mut stream = TcpStream::connect(addr).expect("Failed to connect");
+        stream.write_all(raw.as_bytes()).expect("Failed to send");
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("Failed to read");
+        response
+    }
+
+    fn post(addr: SocketAddr, path: &str, body: &str) -> String {
+        request(
+            addr,
+            &format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", path, body.len(), body),
+        )
+    }
+
+    #[test]
+    fn serves_solutions_on_localhost() {
+        let server = SolveServer::bind("127.0.0.1:0", RunnerConfig::default()).expect("Failed to bind");
+        let addr = server.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let _ = server.run();
+        });
+
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        let solved = post(addr, "/solve/1/1", input);
+        assert!(solved.starts_with("HTTP/1.1 200"));
+        assert!(solved.contains("\"answer\":\"24000\""));
+        assert!(solved.contains("\"elapsed_ms\":"));
+        assert!(post(addr, "/solve/2022/1/2", input).contains("\"answer\":\"45000\""));
+
+        let days = request(addr, "GET /days HTTP/1.1\r\nHost: localhost\r\n\r\n");
+        assert!(days.contains("{\"year\":2022,\"day\":1}"));
+
+        assert!(post(addr, "/solve/1/3", input).starts_with("HTTP/1.1 400"));
+        assert!(post(addr, "/solve/99/1", input).starts_with("HTTP/1.1 404"));
+        let invalid = post(addr, "/solve/1/1", "not a number");
+        assert!(invalid.starts_with("HTTP/1.1 422"));
+        assert!(invalid.contains("\"error\":"));
+    }
+
+    #[test]
+    fn refuses_large_bodies_without_blocking_others() {
+        let server = SolveServer::bind("127.0.0.1:0", RunnerConfig::default()).expect("Failed to bind");
+        let addr = server.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let _ = server.run();
+        });
+
+        // A client that connects and never sends anything.
+        let _stalled = TcpStream::connect(addr).expect("Failed to connect");
+        let huge = request(addr, "POST /solve/1/1 HTTP/1.1\r\nContent-Length: 100000000000000\r\n\r\n");
+        assert!(huge.starts_with("HTTP/1.1 413"));
+        assert!(post(addr, "/solve/1/1", "1000\n\n2000").contains("\"answer\":\"2000\""));
+    }
+
+    #[test]
+    fn refuses_long_or_many_headers() {
+        let server = SolveServer::bind("127.0.0.1:0", RunnerConfig::default()).expect("Failed to bind");
+        let addr = server.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let _ = server.run();
+        });
+
+        // Each request ends where the server stops reading, so nothing is left unread when it closes the connection.
+        let long_path = request(addr, &format!("GET /{}", "a".repeat(MAX_LINE - 5)));
+        assert!(long_path.starts_with("HTTP/1.1 400"));
+        let long_header = request(addr, &format!("GET /days HTTP/1.1\r\nX-Long: {}", "a".repeat(MAX_LINE - 8)));
+        assert!(long_header.starts_with("HTTP/1.1 431"));
+        let many_headers = request(addr, &format!("GET /days HTTP/1.1\r\n{}", "X-Header: 1\r\n".repeat(MAX_HEADERS + 1)));
+        assert!(many_headers.starts_with("HTTP/1.1 431"));
+        let most_headers = request(addr, &format!("GET /days HTTP/1.1\r\n{}\r\n", "X-Header: 1\r\n".repeat(MAX_HEADERS)));
+        assert!(most_headers.starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn escapes_json() {
+        assert_eq!(json_string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+    }
+}
@@ -0,0 +1,91 @@
+// Snapshot assertions for multi-line output like CRT screens and rendered maps.
+//
+// Expected output lives in tests/snapshots/<name>.snap. A mismatch fails with a line diff;
+// running the tests with UPDATE_SNAPSHOTS=1 writes the actual output as the new snapshot instead.
+use std::fs;
+use std::path::PathBuf;
+
+const UPDATE_VAR: &str = "UPDATE_SNAPSHOTS";
+
+#[track_caller]
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+    if std::env::var_os(UPDATE_VAR).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).expect("Failed to create snapshot directory");
+        fs::write(&path, actual).expect("Failed to write snapshot");
+        return;
+    }
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(_) => panic!(
+            "Missing snapshot {}, run with {}=1 to create it. Actual output:\n{}",
+            path.display(),
+            UPDATE_VAR,
+            actual
+        ),
+    };
+    if expected != actual {
+        panic!(
+            "Snapshot {} doesn't match (- expected, + actual), run with {}=1 to accept:\n{}",
+            path.display(),
+            UPDATE_VAR,
+            diff_lines(&expected, actual)
+        );
+    }
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.snap", name))
+}
+
+// Line diff from the longest common subsequence, which is plenty for screen-sized outputs.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let old = expected.lines().collect::<Vec<&str>>();
+    let new = actual.lines().collect::<Vec<&str>>();
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff += &format!("  {}\n", old[i]);
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] > lcs[i + 1][j]) {
+            diff += &format!("+ {}\n", new[j]);
+            j += 1;
+        } else {
+            diff += &format!("- {}\n", old[i]);
+            i += 1;
+        }
+    }
+    // Differences in trailing newlines don't show up line by line.
+    if diff.lines().all(|l| l.starts_with("  ")) {
+        diff += "(only line endings differ)\n";
+    }
+    diff
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn diffs_changed_lines() {
+        let diff = diff_lines("a\nb\nc", "a\nx\nc\nd");
+        assert_eq!(diff, "  a\n- b\n+ x\n  c\n+ d\n");
+        assert!(diff_lines("a\n", "a").ends_with("(only line endings differ)\n"));
+    }
+}
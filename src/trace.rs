@@ -0,0 +1,220 @@
+// Leveled tracing of solver internals, enabled per day at runtime.
+//
+// Solutions emit events with `trace_event!`, which picks up the calling module so each event is
+// attributed to its year and day:
+//
+//     trace_event!(Debug, "cd", dir = dirname, depth = self.path.len());
+//
+// Hooks compile to nothing unless the `trace` feature is enabled, which default builds leave off. With it,
+// a hook's fields are only evaluated when its day has been enabled at that level or above, but every
+// hook still does an atomic load each time it's reached.
+use crate::json_string;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, RwLock};
+
+#[doc(hidden)]
+pub const COMPILED: bool = cfg!(feature = "trace");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl Level {
+    fn name(&self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = TraceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(TraceError("Unknown trace level, expected info, debug or trace.")),
+        }
+    }
+}
+
+// The most verbose level enabled for any day, checked before anything else.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+static FILTERS: RwLock<Vec<((u32, u32), Level)>> = RwLock::new(Vec::new());
+// JSONL destination; events go to stderr as text while this is unset.
+static JSONL_OUTPUT: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+pub fn enable(year: u32, day: u32, level: Level) {
+    let mut filters = FILTERS.write().unwrap();
+    filters.retain(|(target, _)| *target != (year, day));
+    filters.push(((year, day), level));
+    MAX_LEVEL.fetch_max(level as u8, Ordering::Relaxed);
+}
+
+pub fn set_jsonl_output(writer: Box<dyn Write + Send>) {
+    *JSONL_OUTPUT.lock().unwrap() = Some(writer);
+}
+
+pub fn is_enabled(level: Level, module: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    let target = match target_of(module) {
+        Some(target) => target,
+        None => return false,
+    };
+    FILTERS
+        .read()
+        .unwrap()
+        .iter()
+        .any(|(t, max)| *t == target && level <= *max)
+}
+
+pub fn emit(level: Level, module: &str, event: &str, fields: &[(&str, &dyn TraceValue)]) {
+    let (year, day) = target_of(module).unwrap_or((0, 0));
+    let mut output = JSONL_OUTPUT.lock().unwrap();
+    // Tracing must never make a solver fail, so write errors are dropped.
+    let _ = match output.as_mut() {
+        Some(writer) => {
+            let fields = fields
+                .iter()
+                .map(|(key, value)| format!("{}:{}", json_string(key), value.to_json()))
+                .collect::<Vec<String>>();
+            writeln!(
+                writer,
+                "{{\"year\":{},\"day\":{},\"level\":\"{}\",\"event\":{},\"fields\":{{{}}}}}",
+                year,
+                day,
+                level.name(),
+                json_string(event),
+                fields.join(",")
+            )
+        }
+        None => {
+            let fields = fields
+                .iter()
+                .map(|(key, value)| format!(" {}={}", key, value.to_json()))
+                .collect::<String>();
+            writeln!(std::io::stderr(), "[{}.day{} {}] {}{}", year, day, level.name(), event, fields)
+        }
+    };
+}
+
+// Finds the year and day from a module path like `aoc::year2022::day7`.
+fn target_of(module: &str) -> Option<(u32, u32)> {
+    let mut segments = module.split("::");
+    let year = segments.find_map(|s| s.strip_prefix("year")?.parse::<u32>().ok())?;
+    let day = segments.next()?.strip_prefix("day")?.parse::<u32>().ok()?;
+    Some((year, day))
+}
+
+// Values that can be attached to an event as fields.
+pub trait TraceValue {
+    fn to_json(&self) -> String;
+}
+
+macro_rules! display_trace_value {
+    ($($t:ty),*) => {
+        $(impl TraceValue for $t {
+            fn to_json(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+display_trace_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, bool);
+
+impl TraceValue for str {
+    fn to_json(&self) -> String {
+        json_string(self)
+    }
+}
+
+impl TraceValue for String {
+    fn to_json(&self) -> String {
+        json_string(self)
+    }
+}
+
+impl<T: TraceValue + ?Sized> TraceValue for &T {
+    fn to_json(&self) -> String {
+        (**self).to_json()
+    }
+}
+
+impl<A: TraceValue, B: TraceValue> TraceValue for (A, B) {
+    fn to_json(&self) -> String {
+        format!("[{},{}]", self.0.to_json(), self.1.to_json())
+    }
+}
+
+#[macro_export]
+macro_rules! trace_event {
+    ($level:ident, $event:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::COMPILED && $crate::trace::is_enabled($crate::trace::Level::$level, module_path!()) {
+            $crate::trace::emit(
+                $crate::trace::Level::$level,
+                module_path!(),
+                $event,
+                &[$((stringify!($key), &$value as &dyn $crate::trace::TraceValue)),*],
+            );
+        }
+    };
+}
+
+custom_error!(TraceError);
+
+#[cfg(all(test, feature = "trace"))]
+mod test {
+    use super::*;
+    use std::sync::Arc;
+
+    // Shares what was written so the test can read it back after handing the writer over.
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn filters_by_day_and_level() {
+        // A year no solution uses, so hooks in tests running alongside stay quiet.
+        let module = "aoc::year1999::day3::part";
+        assert_eq!(target_of(module), Some((1999, 3)));
+        assert!(!is_enabled(Level::Info, module));
+
+        enable(1999, 3, Level::Debug);
+        assert!(is_enabled(Level::Debug, module));
+        assert!(!is_enabled(Level::Trace, module));
+        assert!(!is_enabled(Level::Info, "aoc::year1999::day4"));
+        assert!(!is_enabled(Level::Info, "aoc::util"));
+
+        let capture = Capture::default();
+        set_jsonl_output(Box::new(capture.clone()));
+        emit(Level::Debug, module, "rest", &[("at", &(500, 8)), ("name", &"a\"b")]);
+        let written = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            written,
+            "{\"year\":1999,\"day\":3,\"level\":\"debug\",\"event\":\"rest\",\"fields\":{\"at\":[500,8],\"name\":\"a\\\"b\"}}\n"
+        );
+        assert_eq!("TRACE".parse::<Level>().unwrap(), Level::Trace);
+    }
+}
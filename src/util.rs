@@ -2,6 +2,17 @@ use std::str::FromStr;
 
 pub type SResult<T> = Result<T, Box<dyn std::error::Error>>;
 
+pub type SolutionLoader = fn() -> SResult<Box<dyn AOCSolution>>;
+pub type SolutionParser = fn(&str) -> SResult<Box<dyn AOCSolution>>;
+
+// A solution as listed in a year's registry: loaded either from its input file or from input text.
+pub struct RegisteredSolution {
+    pub year: u32,
+    pub day: u32,
+    pub load: SolutionLoader,
+    pub parse: SolutionParser,
+}
+
 pub trait AOCSolution {
     fn load_from(input_file_path: &str) -> SResult<Box<Self>>
     where
@@ -13,16 +24,55 @@ pub trait AOCSolution {
     fn part_2(&mut self) -> String {
         "TODO!".to_string()
     }
+
+    // As the parts, for solutions whose input can parse and still have no answer. `solve`, the server and
+    // the runner go through these, so such a failure comes back as an error rather than as the answer.
+    fn try_part_1(&mut self) -> SResult<String> {
+        Ok(self.part_1())
+    }
+    fn try_part_2(&mut self) -> SResult<String> {
+        Ok(self.part_2())
+    }
+
+    // Solutions with tunable puzzle constants expose them here so the runner can override them.
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        None
+    }
+
+    // Solutions with more to say about their input than the answers describe it here, shown with `--report`.
+    fn report(&mut self) -> Option<String> {
+        None
+    }
 }
 
+pub trait PuzzleParams {
+    fn set(&mut self, key: &str, value: &str) -> SResult<()>;
+    fn entries(&self) -> Vec<(&'static str, String)>;
+}
+
+#[allow(unused_macros)]
 macro_rules! solution {
-    (Day $day:expr => $s:ty) => {
+    (Year $year:literal Day $day:literal => $s:ty) => {
         pub fn solution() -> Result<Box<$s>, Box<dyn std::error::Error>> {
-            <$s>::load_from(&format!("input/day{}.txt", $day))
+            <$s>::load_from(&$crate::util::input_path($year, $day))
+        }
+
+        pub fn solution_from_str(input: &str) -> Result<Box<$s>, Box<dyn std::error::Error>> {
+            Ok(Box::new(<$s>::new_from_str(input)?))
+        }
+
+        pub fn registration() -> $crate::util::RegisteredSolution {
+            $crate::util::RegisteredSolution {
+                year: $year,
+                day: $day,
+                load: || Ok(solution()?),
+                parse: |input| Ok(solution_from_str(input)?),
+            }
         }
     };
 }
 
+#[macro_export]
 macro_rules! custom_error {
     ($name:ident) => {
         #[derive(Debug)]
@@ -38,6 +88,97 @@ macro_rules! custom_error {
     }
 }
 
+// As `custom_error!`, for errors that name the piece of input they're about.
+#[macro_export]
+macro_rules! token_error {
+    ($name:ident) => {
+        #[derive(Debug)]
+        pub struct $name(&'static str, String);
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_fmt(format_args!("{} Got '{}'.", self.0, self.1))
+            }
+        }
+
+        impl std::error::Error for $name {}
+    }
+}
+
+// Declares a typed parameter set with defaults, settable by name from strings. A field may follow its
+// default with `=> range` to refuse values the solution can't work with, e.g. `minutes: u32 = 30 => 1..=1000`.
+#[allow(unused_macros)]
+macro_rules! params {
+    ($name:ident { $($field:ident : $ty:ty = $default:expr $(=> $range:expr)?),* $(,)? }) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name {
+            $(pub $field: $ty,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::util::PuzzleParams for $name {
+            fn set(&mut self, key: &str, value: &str) -> $crate::util::SResult<()> {
+                match key {
+                    $(stringify!($field) => {
+                        let value = value
+                            .trim()
+                            .parse::<$ty>()
+                            .map_err(|_| $crate::util::ParamError::new("Invalid value for parameter."))?;
+                        $(if !($range).contains(&value) {
+                            return Err(Box::new($crate::util::ParamError::new("Value out of range for parameter.")));
+                        })?
+                        self.$field = value;
+                    })*
+                    _ => return Err(Box::new($crate::util::ParamError::new("Unknown parameter."))),
+                }
+                Ok(())
+            }
+
+            fn entries(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($field), format!("{}", self.$field)),)*]
+            }
+        }
+    };
+}
+
+custom_error!(ParamError);
+
+impl ParamError {
+    pub fn new(message: &'static str) -> Self {
+        Self(message)
+    }
+}
+
+pub fn input_path(year: u32, day: u32) -> String {
+    format!("input/{}/day{}.txt", year, day)
+}
+
+// Quotes and escapes a string for hand-written JSON output.
+pub fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
 pub fn read_input_to_str(input_path: &str, trim: bool) -> SResult<String> {
     let mut input_contents = std::fs::read_to_string(input_path)?;
     if trim {
@@ -84,7 +225,7 @@ where
 pub fn str_to_grid_info(input: &str) -> (usize, usize) {
     let input = input.trim();
     let mut lines = input.lines();
-    let width = lines.nth(0).map(|l| l.len()).unwrap();
+    let width = lines.next().map(|l| l.len()).unwrap();
     lines = input.lines();
     let height = lines.count();
     (width, height)
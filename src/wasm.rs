@@ -0,0 +1,22 @@
+use wasm_bindgen::prelude::*;
+
+// The browser playground only covers the 2022 calendar.
+const YEAR: u32 = 2022;
+
+// Returns the answer, or a message starting with "Error:" if the input couldn't be solved.
+#[wasm_bindgen]
+pub fn solve(day: u32, part: u32, input: &str) -> String {
+    match crate::solve(YEAR, day, part, input) {
+        Ok(answer) => answer,
+        Err(e) => format!("Error: {}", e),
+    }
+}
+
+#[wasm_bindgen]
+pub fn days() -> Vec<u32> {
+    crate::registry()
+        .iter()
+        .filter(|s| s.year == YEAR)
+        .map(|s| s.day)
+        .collect()
+}
@@ -0,0 +1,187 @@
+use super::ElfManifest;
+use std::fmt::Write;
+
+// One elf's calorie total, with the elf's position in the manifest (starting at 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub index: usize,
+    pub calories: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalorieSummary {
+    pub elves: usize,
+    pub total: u64,
+    pub min: u32,
+    pub max: u32,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+}
+
+// Elves carrying between `start` and `end` calories, inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistogramBucket {
+    pub start: u32,
+    pub end: u32,
+    pub count: usize,
+}
+
+impl ElfManifest {
+    pub fn totals(&self) -> Vec<ElfTotal> {
+        self.inventories
+            .iter()
+            .enumerate()
+            .map(|(index, inv)| ElfTotal {
+                index,
+                calories: inv.get_calories(),
+            })
+            .collect()
+    }
+
+    // Totals from most to fewest calories, ties kept in manifest order.
+    pub fn ranked_totals(&self) -> Vec<ElfTotal> {
+        let mut totals = self.totals();
+        totals.sort_by_key(|t| std::cmp::Reverse(t.calories));
+        totals
+    }
+
+    // 1 for the elf carrying the most; elves with equal totals share a rank.
+    pub fn rank_of(&self, index: usize) -> Option<usize> {
+        let calories = self.inventories.get(index)?.get_calories();
+        let carrying_more = self
+            .inventories
+            .iter()
+            .filter(|inv| inv.get_calories() > calories)
+            .count();
+        Some(carrying_more + 1)
+    }
+
+    // Linearly interpolated between the closest totals, for `p` from 0 to 100.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let sorted = self.sorted_calories();
+        let last = sorted.len().checked_sub(1)?;
+        let position = p / 100.0 * last as f64;
+        let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+        let fraction = position - lower as f64;
+        Some(sorted[lower] as f64 + (sorted[upper] as f64 - sorted[lower] as f64) * fraction)
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.inventories.is_empty() {
+            return None;
+        }
+        Some(self.total_calories() as f64 / self.inventories.len() as f64)
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    // Population standard deviation of the totals.
+    pub fn std_dev(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let variance = self
+            .inventories
+            .iter()
+            .map(|inv| (inv.get_calories() as f64 - mean).powi(2))
+            .sum::<f64>()
+            / self.inventories.len() as f64;
+        Some(variance.sqrt())
+    }
+
+    pub fn summary(&self) -> Option<CalorieSummary> {
+        let sorted = self.sorted_calories();
+        Some(CalorieSummary {
+            elves: sorted.len(),
+            total: self.total_calories(),
+            min: *sorted.first()?,
+            max: *sorted.last()?,
+            mean: self.mean()?,
+            median: self.median()?,
+            std_dev: self.std_dev()?,
+        })
+    }
+
+    // Splits the range from the smallest to the largest total into `buckets` equally wide buckets.
+    pub fn histogram(&self, buckets: usize) -> Vec<HistogramBucket> {
+        let sorted = self.sorted_calories();
+        let (min, max) = match (sorted.first(), sorted.last()) {
+            (Some(min), Some(max)) if buckets > 0 => (*min, *max),
+            _ => return Vec::new(),
+        };
+        let width = ((max - min) / buckets as u32 + 1).max(1);
+        let mut histogram = (0..buckets as u32)
+            .map(|i| HistogramBucket {
+                start: min.saturating_add(i.saturating_mul(width)),
+                end: min.saturating_add((i + 1).saturating_mul(width)).saturating_sub(1),
+                count: 0,
+            })
+            .take_while(|b| b.start <= max)
+            .collect::<Vec<HistogramBucket>>();
+        for calories in sorted {
+            histogram[((calories - min) / width) as usize].count += 1;
+        }
+        histogram
+    }
+
+    // Elves carrying strictly more than `threshold` calories, in manifest order.
+    pub fn elves_above(&self, threshold: u32) -> Vec<ElfTotal> {
+        self.totals()
+            .into_iter()
+            .filter(|t| t.calories > threshold)
+            .collect()
+    }
+
+    // The fewest elves whose totals add up to at least `target`, or None if all of them together fall short.
+    pub fn elves_to_reach(&self, target: u64) -> Option<Vec<ElfTotal>> {
+        let mut reached = 0;
+        let mut elves = Vec::new();
+        for total in self.ranked_totals() {
+            if reached >= target {
+                break;
+            }
+            reached += total.calories as u64;
+            elves.push(total);
+        }
+        (reached >= target).then_some(elves)
+    }
+
+    pub fn calorie_report(&self) -> Option<String> {
+        let summary = self.summary()?;
+        let mut report = String::new();
+        writeln!(report, "Elves: {}, total calories: {}", summary.elves, summary.total).ok()?;
+        writeln!(report, "Min {}, max {}, median {:.1}", summary.min, summary.max, summary.median).ok()?;
+        writeln!(report, "Mean {:.1}, std dev {:.1}", summary.mean, summary.std_dev).ok()?;
+        writeln!(report, "Top {}:", self.params.top_n).ok()?;
+        for (rank, total) in self.ranked_totals().iter().take(self.params.top_n).enumerate() {
+            writeln!(report, "  {}. elf {} with {}", rank + 1, total.index, total.calories).ok()?;
+        }
+        writeln!(report, "Histogram:").ok()?;
+        for bucket in self.histogram(self.params.histogram_buckets) {
+            writeln!(
+                report,
+                "  {:>7} - {:>7} | {:<4} {}",
+                bucket.start,
+                bucket.end,
+                bucket.count,
+                "#".repeat(bucket.count.min(60))
+            )
+            .ok()?;
+        }
+        Some(report)
+    }
+
+    fn sorted_calories(&self) -> Vec<u32> {
+        let mut calories = self.get_all_calorie_totals();
+        calories.sort_unstable();
+        calories
+    }
+
+    fn total_calories(&self) -> u64 {
+        self.inventories.iter().map(|inv| inv.get_calories() as u64).sum()
+    }
+}
@@ -0,0 +1,154 @@
+use super::{ElfInventory, ElfManifest};
+use crate::util::{read_input_to_str, SResult};
+use std::fmt::Write;
+use std::path::Path;
+
+// Formats a manifest can be read from and written to. The puzzle's own format is `Text`.
+//
+// Text has no way to express an elf with no items, so manifests with one can't be written as text.
+// JSON is one array of calorie entries per elf: `[[1000,2000],[4000]]`.
+// CSV has a header and one row per item: `elf,item,calories`, with elves and items numbered from 0.
+// An elf with no items gets a row of its own with the last two columns empty, like `2,,`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+impl ManifestFormat {
+    // Picks the format from a file extension, treating anything unrecognised as text.
+    pub fn from_path(path: &str) -> Self {
+        match Path::new(path).extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ManifestFormat::Json,
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ManifestFormat::Csv,
+            _ => ManifestFormat::Text,
+        }
+    }
+}
+
+const CSV_HEADER: &str = "elf,item,calories";
+
+impl ElfManifest {
+    pub fn from_entries(elves: Vec<Vec<u32>>) -> Self {
+        Self::from_inventories(elves.into_iter().map(ElfInventory::from_entries).collect())
+    }
+
+    pub fn parse_as(input: &str, format: ManifestFormat) -> SResult<Self> {
+        match format {
+            ManifestFormat::Text => Self::new_from_str(input),
+            ManifestFormat::Json => Self::new_from_json(input),
+            ManifestFormat::Csv => Self::new_from_csv(input),
+        }
+    }
+
+    pub fn write_as(&self, format: ManifestFormat) -> SResult<String> {
+        match format {
+            ManifestFormat::Text => self.to_text(),
+            ManifestFormat::Json => self.to_json(),
+            ManifestFormat::Csv => Ok(self.to_csv()),
+        }
+    }
+
+    pub fn load(path: &str) -> SResult<Self> {
+        let input = read_input_to_str(path, true)?;
+        Self::parse_as(&input, ManifestFormat::from_path(path))
+    }
+
+    pub fn save(&self, path: &str) -> SResult<()> {
+        std::fs::write(path, self.write_as(ManifestFormat::from_path(path))?)?;
+        Ok(())
+    }
+
+    // The puzzle format: one item per line, with a blank line between elves.
+    pub fn to_text(&self) -> SResult<String> {
+        if self.inventories.iter().any(|inv| inv.entries().is_empty()) {
+            return Err(Box::new(ManifestFormatError("Elves with no items can't be written as text.")));
+        }
+        Ok(self
+            .inventories
+            .iter()
+            .map(|inv| {
+                inv.entries()
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n")
+            + "\n")
+    }
+
+    pub fn to_json(&self) -> SResult<String> {
+        let elves = self
+            .inventories
+            .iter()
+            .map(|inv| inv.entries())
+            .collect::<Vec<&[u32]>>();
+        Ok(serde_json::to_string(&elves)?)
+    }
+
+    pub fn new_from_json(input: &str) -> SResult<Self> {
+        let elves: Vec<Vec<u32>> = serde_json::from_str(input)?;
+        Ok(Self::from_entries(elves))
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", CSV_HEADER);
+        // Writing to a String can't fail.
+        for (elf, inv) in self.inventories.iter().enumerate() {
+            if inv.entries().is_empty() {
+                writeln!(csv, "{},,", elf).unwrap();
+            }
+            for (item, calories) in inv.entries().iter().enumerate() {
+                writeln!(csv, "{},{},{}", elf, item, calories).unwrap();
+            }
+        }
+        csv
+    }
+
+    // Rows may come in any order; items are put back in order of their `item` column, which must
+    // number each elf's items from 0 with no repeats or gaps.
+    pub fn new_from_csv(input: &str) -> SResult<Self> {
+        let mut lines = input.lines().map(|l| l.trim()).filter(|l| !l.is_empty());
+        if lines.next().map(|h| h.replace(' ', "")) != Some(CSV_HEADER.to_string()) {
+            return Err(Box::new(ManifestFormatError("Missing CSV header, expected elf,item,calories.")));
+        }
+        // (elf, item and calories), with None for an empty elf's row.
+        let mut rows = Vec::new();
+        for line in lines {
+            let fields = line.split(',').map(|f| f.trim()).collect::<Vec<&str>>();
+            match fields.as_slice() {
+                [elf, "", ""] => rows.push((elf.parse::<usize>()?, None)),
+                [elf, item, calories] => {
+                    rows.push((elf.parse::<usize>()?, Some((item.parse::<usize>()?, calories.parse::<u32>()?))))
+                }
+                _ => return Err(Box::new(ManifestFormatError("Expected three columns in each CSV row."))),
+            }
+        }
+        rows.sort_unstable();
+
+        let mut elves: Vec<Option<Vec<u32>>> = Vec::new();
+        for (elf, item) in rows {
+            if elf == elves.len() {
+                elves.push(item.map(|_| Vec::new()));
+                if item.is_none() {
+                    continue;
+                }
+            } else if elf > elves.len() {
+                return Err(Box::new(ManifestFormatError("Elf numbers in the CSV must not skip any elf.")));
+            }
+            match (&mut elves[elf], item) {
+                (Some(entries), Some((item, calories))) if item == entries.len() => entries.push(calories),
+                (Some(_), Some(_)) => {
+                    return Err(Box::new(ManifestFormatError("Item numbers in the CSV must count up from 0 for each elf.")))
+                }
+                _ => return Err(Box::new(ManifestFormatError("An elf marked empty in the CSV can't have any other rows."))),
+            }
+        }
+        Ok(Self::from_entries(elves.into_iter().map(Option::unwrap_or_default).collect()))
+    }
+}
+
+custom_error!(ManifestFormatError);
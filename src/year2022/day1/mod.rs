@@ -0,0 +1,295 @@
+use crate::util::*;
+
+mod analytics;
+mod io;
+mod rebalance;
+mod top_n;
+
+pub use analytics::{CalorieSummary, ElfTotal, HistogramBucket};
+pub use io::{ManifestFormat, ManifestFormatError};
+pub use rebalance::{ItemTransfer, PlannerMode, RebalanceError, RebalanceGoal, RebalancePlan, EXACT_ITEM_LIMIT};
+pub use top_n::{stream_top_n, TopElves, TopN};
+
+solution!(Year 2022 Day 1 => ElfManifest);
+
+params!(ManifestParams {
+    top_n: usize = 3 => ..=100_000,
+    histogram_buckets: usize = 10 => ..=10_000,
+});
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElfManifest {
+    inventories: Vec<ElfInventory>,
+    params: ManifestParams,
+}
+
+impl AOCSolution for ElfManifest {
+    fn load_from(input_file_path: &str) -> Result<Box<Self>, Box<dyn std::error::Error>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        self.get_max_calories().to_string()
+    }
+
+    fn part_2(&mut self) -> String {
+        self.top_n(self.params.top_n).sum.to_string()
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
+
+    fn report(&mut self) -> Option<String> {
+        self.calorie_report()
+    }
+}
+
+impl ElfManifest {
+    pub fn new_from_file(input_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    // Elves are separated by one or more blank lines, counting lines of only whitespace, the same as `stream_top_n`.
+    pub fn new_from_str(input: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let sections = input
+            .lines()
+            .collect::<Vec<&str>>()
+            .split(|line| line.trim().is_empty())
+            .filter(|section| !section.is_empty())
+            .map(|section| section.join("\n"))
+            .collect();
+        Self::new(sections)
+    }
+
+    pub fn new(input_sections: Vec<String>) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut inventories = Vec::new();
+        for section in input_sections.iter() {
+            let inv = ElfInventory::new(section)?;
+            inventories.push(inv);
+        }
+        Ok(Self::from_inventories(inventories))
+    }
+
+    pub fn from_inventories(inventories: Vec<ElfInventory>) -> Self {
+        Self {
+            inventories,
+            params: ManifestParams::default(),
+        }
+    }
+
+    pub fn get_max_calories(&self) -> u32 {
+        self.get_all_calorie_totals().into_iter().max().unwrap_or(0)
+    }
+
+    // Sorts every total; `top_n` gets the same sum with a bounded heap.
+    pub fn get_top_n_calorie_sum(&self, n: usize) -> u32 {
+        let mut cal_vec: Vec<u32> = self.get_all_calorie_totals();
+        cal_vec.sort_unstable();
+        cal_vec.iter().rev().take(n).sum()
+    }
+
+    pub fn inventories(&self) -> &[ElfInventory] {
+        &self.inventories
+    }
+
+    fn get_all_calorie_totals(&self) -> Vec<u32> {
+        self.inventories
+            .iter()
+            .map(|inv| inv.get_calories())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfInventory {
+    entries: Vec<u32>,
+    // Summed once up front, since every query over the manifest needs it.
+    calories: u32,
+}
+
+impl ElfInventory {
+    pub fn new(repr: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let entries = parse_lines_into::<u32>(repr)?;
+
+        Ok(Self::from_entries(entries))
+    }
+
+    pub fn from_entries(entries: Vec<u32>) -> Self {
+        let calories = entries.iter().sum();
+        Self { entries, calories }
+    }
+
+    pub fn entries(&self) -> &[u32] {
+        &self.entries
+    }
+
+    pub fn get_calories(&self) -> u32 {
+        self.calories
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_INPUT: &str = "
+        1000
+        2000
+        3000
+
+        4000
+
+        5000
+        6000
+
+        7000
+        8000
+        9000
+
+        10000
+    ";
+
+    #[test]
+    fn given_test() {
+        let manifest = ElfManifest::new_from_str(TEST_INPUT).expect("Failed to parse manifest.");
+        let result = manifest.get_max_calories();
+        assert!(result == 24000);
+    }
+
+    #[test]
+    fn check_max_is_sum_1() {
+        let manifest = ElfManifest::new_from_str(TEST_INPUT).expect("Failed to parse manifest.");
+
+        let result1 = manifest.get_max_calories();
+        let result2 = manifest.get_top_n_calorie_sum(1);
+
+        assert!(result1 == result2);
+    }
+
+    #[test]
+    fn analytics() {
+        let manifest = ElfManifest::new_from_str(TEST_INPUT).expect("Failed to parse manifest.");
+        let totals = manifest.totals();
+        assert_eq!(totals[3], ElfTotal { index: 3, calories: 24000 });
+        assert_eq!(manifest.rank_of(3), Some(1));
+        assert_eq!(manifest.rank_of(1), Some(5));
+        assert_eq!(manifest.rank_of(5), None);
+
+        assert_eq!(manifest.median(), Some(10000.0));
+        assert_eq!(manifest.percentile(25.0), Some(6000.0));
+        assert_eq!(manifest.percentile(90.0), Some(18800.0));
+        assert_eq!(manifest.mean(), Some(11000.0));
+        assert!((manifest.std_dev().unwrap() - 6985.7).abs() < 0.1);
+
+        let histogram = manifest.histogram(2);
+        assert_eq!(histogram[0], HistogramBucket { start: 4000, end: 14000, count: 4 });
+        assert_eq!(histogram[1].count, 1);
+
+        let above = manifest.elves_above(10000).iter().map(|t| t.index).collect::<Vec<usize>>();
+        assert_eq!(above, vec![2, 3]);
+        let needed = manifest.elves_to_reach(35000).unwrap();
+        assert_eq!(needed.iter().map(|t| t.index).collect::<Vec<usize>>(), vec![3, 2]);
+        assert!(manifest.elves_to_reach(60000).is_none());
+        assert!(manifest.calorie_report().unwrap().contains("1. elf 3 with 24000"));
+    }
+
+    #[test]
+    fn heap_top_n_matches_sort() {
+        let manifest = ElfManifest::new_from_str(TEST_INPUT).expect("Failed to parse manifest.");
+        for n in 0..7 {
+            assert_eq!(manifest.top_n(n).sum, manifest.get_top_n_calorie_sum(n) as u64);
+        }
+        let top = manifest.top_n(3);
+        assert_eq!(top.elves.iter().map(|e| e.index).collect::<Vec<usize>>(), vec![3, 2, 4]);
+        assert_eq!(top.sum, 45000);
+
+        let streamed = stream_top_n(TEST_INPUT.as_bytes(), 3).expect("Failed to stream manifest.");
+        assert_eq!(streamed, top);
+
+        // Ties go to the earlier elf.
+        let tied = stream_top_n("5\n\n7\n\n5\n\n7".as_bytes(), 3).unwrap();
+        assert_eq!(tied.elves.iter().map(|e| e.index).collect::<Vec<usize>>(), vec![1, 3, 0]);
+        assert!(stream_top_n("5\nfive".as_bytes(), 1).is_err());
+
+        // Both readers split elves at the same blank lines, including ones holding only whitespace.
+        let spaced = "5\n  \n7\n\n\n\n2\n3\n\t\n\n1\n";
+        let manifest = ElfManifest::new_from_str(spaced).unwrap();
+        assert_eq!(manifest.inventories().len(), 4);
+        assert_eq!(stream_top_n(spaced.as_bytes(), 4).unwrap(), manifest.top_n(4));
+    }
+
+    #[test]
+    fn rebalancing() {
+        let manifest = ElfManifest::new_from_str(TEST_INPUT).expect("Failed to parse manifest.");
+        let exact = manifest
+            .plan_rebalance(RebalanceGoal::MinimizeMax, PlannerMode::Exact)
+            .expect("Failed to plan.");
+        assert_eq!(exact.max_load, 11000);
+        assert_eq!(exact.loads, vec![11000; 5]);
+
+        let heuristic = manifest
+            .plan_rebalance(RebalanceGoal::MinimizeMax, PlannerMode::Heuristic)
+            .expect("Failed to plan.");
+        assert!(heuristic.max_load >= exact.max_load && heuristic.max_load < 24000);
+
+        // Replaying the transfers must give the reported loads.
+        let mut loads = manifest.totals().iter().map(|t| t.calories).collect::<Vec<u32>>();
+        for t in heuristic.transfers.iter() {
+            assert_eq!(manifest.inventories()[t.from].entries()[t.item], t.calories);
+            loads[t.from] -= t.calories;
+            loads[t.to] += t.calories;
+        }
+        assert_eq!(loads, heuristic.loads);
+
+        let target = manifest.plan_rebalance(RebalanceGoal::Target(15000), PlannerMode::Heuristic).unwrap();
+        assert!(target.max_load <= 15000);
+        assert!(manifest.plan_rebalance(RebalanceGoal::Target(10999), PlannerMode::Exact).is_err());
+    }
+
+    #[test]
+    fn round_trips_formats() {
+        let manifest = ElfManifest::new_from_str(TEST_INPUT).expect("Failed to parse manifest.");
+        let entries = |m: &ElfManifest| {
+            m.inventories()
+                .iter()
+                .map(|inv| inv.entries().to_vec())
+                .collect::<Vec<Vec<u32>>>()
+        };
+        for format in [ManifestFormat::Text, ManifestFormat::Json, ManifestFormat::Csv] {
+            let written = manifest.write_as(format).expect("Failed to write manifest.");
+            let read = ElfManifest::parse_as(&written, format).expect("Failed to read manifest back.");
+            assert_eq!(read, manifest);
+        }
+        // Empty elves at the start, middle and end survive JSON and CSV, but the puzzle's text has no way to write them.
+        let with_empty = ElfManifest::from_entries(vec![vec![], vec![1], vec![], vec![2, 3], vec![]]);
+        for format in [ManifestFormat::Json, ManifestFormat::Csv] {
+            let written = with_empty.write_as(format).expect("Failed to write manifest.");
+            assert_eq!(ElfManifest::parse_as(&written, format).unwrap(), with_empty);
+        }
+        assert!(with_empty.to_text().is_err());
+
+        let json = manifest.to_json().unwrap();
+        assert!(json.starts_with("[[1000,2000,3000],[4000]"));
+        let csv = manifest.to_csv();
+        assert!(csv.starts_with("elf,item,calories\n0,0,1000\n0,1,2000\n"));
+
+        let shuffled = ElfManifest::new_from_csv("elf,item,calories\n1,0,5\n0,1,2\n0,0,1").unwrap();
+        assert_eq!(entries(&shuffled), vec![vec![1, 2], vec![5]]);
+        assert!(ElfManifest::new_from_csv("elf,item,calories\n1,0,5").is_err());
+        assert!(ElfManifest::new_from_csv("0,0,5").is_err());
+        assert_eq!(entries(&ElfManifest::new_from_csv("elf,item,calories\n0,,\n1,0,5").unwrap()), vec![vec![], vec![5]]);
+        // Repeated or missing items, and an elf both empty and not.
+        assert!(ElfManifest::new_from_csv("elf,item,calories\n0,0,1\n0,0,2").is_err());
+        assert!(ElfManifest::new_from_csv("elf,item,calories\n0,0,1\n0,2,2").is_err());
+        assert!(ElfManifest::new_from_csv("elf,item,calories\n0,1,1").is_err());
+        assert!(ElfManifest::new_from_csv("elf,item,calories\n0,,\n0,0,1").is_err());
+        assert!(ElfManifest::new_from_csv("elf,item,calories\n0,,1").is_err());
+        assert!(ElfManifest::new_from_json("{\"elves\": 1}").is_err());
+        assert_eq!(ManifestFormat::from_path("input/inventory.CSV"), ManifestFormat::Csv);
+    }
+}
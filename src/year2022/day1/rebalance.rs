@@ -0,0 +1,229 @@
+use super::ElfManifest;
+
+// Exact planning searches every assignment of items to elves, so it's only offered for small manifests.
+pub const EXACT_ITEM_LIMIT: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RebalanceGoal {
+    // Make the heaviest load as light as possible.
+    MinimizeMax,
+    // Stop as soon as no elf carries more than this many calories.
+    Target(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlannerMode {
+    // Moves and swaps items between heavy and light elves until nothing improves. Fast, usually close to optimal.
+    Heuristic,
+    // Branch and bound over every assignment. Optimal, but limited to `EXACT_ITEM_LIMIT` items.
+    Exact,
+}
+
+// Moves the `item`th entry of elf `from`'s inventory to elf `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemTransfer {
+    pub from: usize,
+    pub item: usize,
+    pub to: usize,
+    pub calories: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebalancePlan {
+    pub transfers: Vec<ItemTransfer>,
+    // Each elf's calories once the transfers are made.
+    pub loads: Vec<u32>,
+    pub max_load: u32,
+}
+
+// One food item, where it started and which elf it's assigned to.
+#[derive(Clone, Copy)]
+struct Item {
+    origin: usize,
+    position: usize,
+    calories: u32,
+    elf: usize,
+}
+
+impl ElfManifest {
+    pub fn plan_rebalance(&self, goal: RebalanceGoal, mode: PlannerMode) -> Result<RebalancePlan, RebalanceError> {
+        let mut items = self
+            .inventories
+            .iter()
+            .enumerate()
+            .flat_map(|(elf, inv)| {
+                inv.entries().iter().enumerate().map(move |(position, calories)| Item {
+                    origin: elf,
+                    position,
+                    calories: *calories,
+                    elf,
+                })
+            })
+            .collect::<Vec<Item>>();
+        let elves = self.inventories.len();
+        if elves == 0 {
+            return Err(RebalanceError("Nothing to rebalance, the manifest is empty."));
+        }
+
+        let target = match goal {
+            RebalanceGoal::MinimizeMax => None,
+            RebalanceGoal::Target(target) => Some(target),
+        };
+        if mode == PlannerMode::Exact && items.len() > EXACT_ITEM_LIMIT {
+            return Err(RebalanceError("Too many items for an exact plan, use the heuristic planner."));
+        }
+        improve_locally(&mut items, elves, target);
+        if mode == PlannerMode::Exact {
+            // The heuristic plan is the bound to beat; keep it unless the search finds a lighter maximum.
+            let bound = max_load(&items, elves);
+            if let Some(optimal) = search_exact(&items, elves, bound) {
+                items = optimal;
+            }
+        }
+
+        let loads = loads(&items, elves);
+        let max_load = loads.iter().copied().max().unwrap_or(0);
+        if let Some(target) = target {
+            if max_load > target {
+                return Err(RebalanceError("No plan found that keeps every elf within the target."));
+            }
+        }
+        let mut transfers = items
+            .iter()
+            .filter(|item| item.elf != item.origin)
+            .map(|item| ItemTransfer {
+                from: item.origin,
+                item: item.position,
+                to: item.elf,
+                calories: item.calories,
+            })
+            .collect::<Vec<ItemTransfer>>();
+        transfers.sort_by_key(|t| (t.from, t.item));
+        Ok(RebalancePlan {
+            transfers,
+            loads,
+            max_load,
+        })
+    }
+}
+
+fn loads(items: &[Item], elves: usize) -> Vec<u32> {
+    let mut loads = vec![0; elves];
+    for item in items {
+        loads[item.elf] += item.calories;
+    }
+    loads
+}
+
+fn max_load(items: &[Item], elves: usize) -> u32 {
+    loads(items, elves).into_iter().max().unwrap_or(0)
+}
+
+// Starting from where the items already are, repeatedly moves an item off the heaviest elf, or swaps
+// one for a lighter item, whichever evens the loads out most. Every step lowers the sum of squared
+// loads, so this always terminates, and starting in place keeps the number of transfers down.
+fn improve_locally(items: &mut [Item], elves: usize, target: Option<u32>) {
+    loop {
+        let loads = loads(items, elves);
+        let heaviest = (0..elves).max_by_key(|e| (loads[*e], std::cmp::Reverse(*e))).unwrap();
+        if target.is_some_and(|t| loads[heaviest] <= t) {
+            return;
+        }
+
+        // (resulting larger load of the pair, item to move, elf to move it to, item to swap back)
+        let mut best: Option<(u32, usize, usize, Option<usize>)> = None;
+        for (i, item) in items.iter().enumerate().filter(|(_, item)| item.elf == heaviest) {
+            for other in (0..elves).filter(|e| *e != heaviest) {
+                let gap = loads[heaviest] - loads[other];
+                if item.calories < gap {
+                    let worst = (loads[heaviest] - item.calories).max(loads[other] + item.calories);
+                    if best.is_none_or(|b| worst < b.0) {
+                        best = Some((worst, i, other, None));
+                    }
+                }
+                for (j, swap) in items.iter().enumerate().filter(|(_, s)| s.elf == other) {
+                    if swap.calories < item.calories && item.calories - swap.calories < gap {
+                        let delta = item.calories - swap.calories;
+                        let worst = (loads[heaviest] - delta).max(loads[other] + delta);
+                        if best.is_none_or(|b| worst < b.0) {
+                            best = Some((worst, i, other, Some(j)));
+                        }
+                    }
+                }
+            }
+        }
+
+        match best {
+            Some((_, i, other, swap)) => {
+                items[i].elf = other;
+                if let Some(j) = swap {
+                    items[j].elf = heaviest;
+                }
+            }
+            None => return,
+        }
+    }
+}
+
+// Branch and bound for an assignment whose heaviest load is below `bound`. Items are placed largest
+// first and tried on their own elf before any other, so optimal plans tend to need few transfers.
+fn search_exact(items: &[Item], elves: usize, bound: u32) -> Option<Vec<Item>> {
+    let mut order = items.to_vec();
+    order.sort_by_key(|item| std::cmp::Reverse(item.calories));
+    let total: u32 = order.iter().map(|item| item.calories).sum();
+    let largest = order.first().map_or(0, |item| item.calories);
+    let lower_bound = largest.max(total.div_ceil(elves as u32));
+
+    let mut search = ExactSearch {
+        items: order,
+        loads: vec![0; elves],
+        best_max: bound,
+        best: None,
+        lower_bound,
+    };
+    search.place(0);
+    search.best
+}
+
+struct ExactSearch {
+    items: Vec<Item>,
+    loads: Vec<u32>,
+    best_max: u32,
+    best: Option<Vec<Item>>,
+    lower_bound: u32,
+}
+
+impl ExactSearch {
+    fn place(&mut self, idx: usize) {
+        if self.best_max <= self.lower_bound {
+            return;
+        }
+        if idx == self.items.len() {
+            self.best_max = self.loads.iter().copied().max().unwrap_or(0);
+            self.best = Some(self.items.clone());
+            return;
+        }
+        let item = self.items[idx];
+        let mut candidates = (0..self.loads.len()).collect::<Vec<usize>>();
+        candidates.sort_by_key(|e| (*e != item.origin, self.loads[*e]));
+        let mut tried_loads = Vec::new();
+        for elf in candidates {
+            let load = self.loads[elf];
+            if load + item.calories >= self.best_max {
+                continue;
+            }
+            // Another elf with the same load leads to the same maximum, so only its own elf gets a second look.
+            if elf != item.origin && tried_loads.contains(&load) {
+                continue;
+            }
+            tried_loads.push(load);
+            self.loads[elf] += item.calories;
+            self.items[idx].elf = elf;
+            self.place(idx + 1);
+            self.loads[elf] -= item.calories;
+        }
+        self.items[idx].elf = item.elf;
+    }
+}
+
+custom_error!(RebalanceError);
@@ -0,0 +1,89 @@
+use super::{ElfManifest, ElfTotal};
+use crate::util::SResult;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+// The elves carrying the most calories, from most to fewest, and their combined total.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopElves {
+    pub elves: Vec<ElfTotal>,
+    pub sum: u64,
+}
+
+// Keeps the `n` largest totals pushed so far in a min-heap, so memory stays at O(n) no matter how
+// many elves go past. Ties go to the elf seen first, matching `ElfManifest::ranked_totals`.
+pub struct TopN {
+    n: usize,
+    // Ordered so the top of the heap is the elf that would be dropped next: fewest calories, then latest index.
+    heap: BinaryHeap<Reverse<(u32, Reverse<usize>)>>,
+}
+
+impl TopN {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: ElfTotal) {
+        if self.n == 0 {
+            return;
+        }
+        let key = Reverse((elf.calories, Reverse(elf.index)));
+        if self.heap.len() < self.n {
+            self.heap.push(key);
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if key < *smallest {
+                *smallest = key;
+            }
+        }
+    }
+
+    pub fn finish(self) -> TopElves {
+        let elves = self
+            .heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| ElfTotal { index, calories })
+            .collect::<Vec<ElfTotal>>();
+        let sum = elves.iter().map(|e| e.calories as u64).sum();
+        TopElves { elves, sum }
+    }
+}
+
+impl ElfManifest {
+    pub fn top_n(&self, n: usize) -> TopElves {
+        let mut top = TopN::new(n);
+        for total in self.totals() {
+            top.push(total);
+        }
+        top.finish()
+    }
+}
+
+// Finds the top `n` elves straight from a manifest's text, one line at a time, without keeping
+// the whole manifest in memory. Elves are numbered as `ElfManifest` would number them.
+pub fn stream_top_n<R: BufRead>(reader: R, n: usize) -> SResult<TopElves> {
+    let mut top = TopN::new(n);
+    let mut index = 0;
+    let mut current: Option<u32> = None;
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            if let Some(calories) = current.take() {
+                top.push(ElfTotal { index, calories });
+                index += 1;
+            }
+            continue;
+        }
+        let item = line.parse::<u32>()?;
+        current = Some(current.unwrap_or(0) + item);
+    }
+    if let Some(calories) = current {
+        top.push(ElfTotal { index, calories });
+    }
+    Ok(top.finish())
+}
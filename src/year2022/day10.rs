@@ -1,8 +1,9 @@
 use crate::{AOCSolution, read_input_to_str, parse_lines_into};
 use std::str::FromStr;
 
-solution!(Day 10 => CPU);
+solution!(Year 2022 Day 10 => CPU);
 
+#[allow(clippy::upper_case_acronyms)]
 pub struct CPU {
     clock: u32,
     register: i32,
@@ -25,6 +26,7 @@ impl AOCSolution for CPU {
     }
     
     fn part_2(&mut self) -> String {
+        self.run();
         format!("\n{}", self.show_screen())
     }
 }
@@ -107,7 +109,7 @@ impl FromStr for CPUInstruction {
     type Err = CPUInstructionParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let sections = s.split(' ').collect::<Vec<&str>>();
-        if sections.len() > 2 || sections.len() == 0 {
+        if sections.len() > 2 || sections.is_empty() {
             return Err(CPUInstructionParseError("Invalid instruction length."));
         }
         match sections[0] {
@@ -123,6 +125,7 @@ impl FromStr for CPUInstruction {
 
 custom_error!(CPUInstructionParseError);
 
+#[allow(clippy::upper_case_acronyms)]
 struct CRT {
     pixels: Vec<bool>,
     clock: u32,
@@ -322,6 +325,6 @@ noop
         let mut cpu = CPU::new_from_str(input_str).expect("Unable to parse");
         cpu.run();
         assert_eq!(cpu.get_relevant_sum(), 13140);
-        println!("{}", cpu.show_screen()); // Visual check
+        crate::snapshot::assert_snapshot("2022_day10_screen", &cpu.show_screen());
     }
 }
\ No newline at end of file
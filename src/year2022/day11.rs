@@ -1,13 +1,19 @@
 use std::{str::FromStr, fmt::Debug};
 
-use crate::util::{convert_str_to_sections, read_input_to_str, AOCSolution};
+use crate::util::{convert_str_to_sections, read_input_to_str, AOCSolution, PuzzleParams, SResult};
 
-solution!(Day 11 => KeepAway);
+solution!(Year 2022 Day 11 => KeepAway);
+
+params!(KeepAwayParams {
+    part_1_rounds: usize = 20,
+    part_2_rounds: usize = 10000,
+});
 
 pub struct KeepAway {
     input: String,
     monkeys: Vec<Monkey>,
     modulus: usize,
+    params: KeepAwayParams,
 }
 
 impl AOCSolution for KeepAway {
@@ -18,14 +24,26 @@ impl AOCSolution for KeepAway {
     }
     
     fn part_1(&mut self) -> String {
-        self.run_n_rounds(20, true);
-        format!("{}", self.calculate_monkey_business())
+        self.try_part_1().unwrap_or_else(|e| e.to_string())
     }
     
     fn part_2(&mut self) -> String {
-        self.reload().unwrap();
-        self.run_n_rounds(10000, false);
-        format!("{}", self.calculate_monkey_business())
+        self.try_part_2().unwrap_or_else(|e| e.to_string())
+    }
+
+    fn try_part_1(&mut self) -> SResult<String> {
+        self.run_n_rounds(self.params.part_1_rounds, true)?;
+        Ok(format!("{}", self.calculate_monkey_business()))
+    }
+
+    fn try_part_2(&mut self) -> SResult<String> {
+        self.reload()?;
+        self.run_n_rounds(self.params.part_2_rounds, false)?;
+        Ok(format!("{}", self.calculate_monkey_business()))
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
     }
 }
 
@@ -50,33 +68,38 @@ impl KeepAway {
         Ok(Self {
             input: input_str.to_string(),
             monkeys,
-            modulus
+            modulus,
+            params: KeepAwayParams::default(),
         })
     }
     
-    pub fn run_n_rounds(&mut self, n: usize, reduce: bool) {
+    // Without the modulus, worry levels can outgrow a usize when part 1 runs for more rounds than the puzzle's.
+    pub fn run_n_rounds(&mut self, n: usize, reduce: bool) -> Result<(), KeepAwayError> {
         for _ in 0..n {
-            self.run_round(reduce);
+            self.run_round(reduce).ok_or(KeepAwayError("Worry levels grew too large to track"))?;
         }
+        Ok(())
     }
     
-    fn run_round(&mut self, reduce: bool) {
+    fn run_round(&mut self, reduce: bool) -> Option<()> {
         for monkey_idx in 0..self.monkeys.len() {
-            let insp_count = self.handle_monkey_actions(monkey_idx, reduce);
+            let insp_count = self.handle_monkey_actions(monkey_idx, reduce)?;
             self.monkeys[monkey_idx].items = Vec::new();
             self.monkeys[monkey_idx].inspection_count += insp_count;
         }
+        Some(())
     }
     
-    fn handle_monkey_actions(&mut self, monkey_idx: usize, reduce: bool) -> usize{
+    fn handle_monkey_actions(&mut self, monkey_idx: usize, reduce: bool) -> Option<usize> {
         let monkey = &self.monkeys[monkey_idx];
-        let transfers = monkey.execute_turn(reduce, self.modulus);
+        let transfers = monkey.execute_turn(reduce, self.modulus)?;
         let num_inspections = transfers.len();
         for transfer in transfers {
+            trace_event!(Trace, "transfer", from = monkey_idx, to = transfer.target, item = transfer.item);
             let target_monkey = &mut self.monkeys[transfer.target];
             target_monkey.items.push(transfer.item);
         }
-        num_inspections
+        Some(num_inspections)
     }
     
     pub fn calculate_monkey_business(&self) -> usize {
@@ -88,11 +111,15 @@ impl KeepAway {
     }
 }
 
+// None when the new worry level doesn't fit.
+type MonkeyOperation = Box<dyn Fn(usize) -> Option<usize>>;
+type MonkeyTest = Box<dyn Fn(usize) -> bool>;
+
 struct Monkey {
     items: Vec<usize>,
     inspection_count: usize,
-    operation: Box<dyn Fn(usize) -> usize>,
-    test: Box<dyn Fn(usize) -> bool>,
+    operation: MonkeyOperation,
+    test: MonkeyTest,
     test_val: usize,
     true_monkey: usize,
     false_monkey: usize,
@@ -134,46 +161,39 @@ impl Monkey {
         }
         Ok(sections[1]
             .split(',')
-            .map(|e| {
-                e
-                    .trim()
-                    .parse::<usize>()
-                    .map_err(|_| MonkeyParseError("Invalid item #"))
-            })
-            .filter(|v| v.is_ok())
-            .map(|v| v.unwrap())
+            .filter_map(|e| e.trim().parse::<usize>().ok())
             .collect::<Vec<usize>>())
     }
     
-    fn parse_operation(s: &str) -> Result<Box<dyn Fn(usize) -> usize>, MonkeyParseError> {
+    fn parse_operation(s: &str) -> Result<MonkeyOperation, MonkeyParseError> {
         let sections = s.split(':').collect::<Vec<&str>>();
         if sections.len() != 2 {
             return Err(MonkeyParseError("Invalid operation line"));
         }
         if let Some((_, remaining)) = sections[1].split_once("old") {
-            let op_def = remaining.trim().split_whitespace().collect::<Vec<&str>>();
+            let op_def = remaining.split_whitespace().collect::<Vec<&str>>();
             if op_def.len() != 2 {
                 return Err(MonkeyParseError("Invalid number of rhs args"));
             }
             let reflexive = op_def.last().unwrap() == &"old";
             let op_val = op_def[1].parse::<usize>().unwrap_or(0);
             match (op_def[0], reflexive) {
-                ("+", false) => Ok(Box::new(move |item| item + op_val)),
-                ("+", true)  => Ok(Box::new(move |item| item + item)),
-                ("*", false) => Ok(Box::new(move |item| item * op_val)),
-                ("*", true)  => Ok(Box::new(move |item| item * item)),
-                ("-", false) => Ok(Box::new(move |item| item - op_val)),
-                ("-", true)  => Ok(Box::new(move |_| 0)),
-                ("/", false) => Ok(Box::new(move |item| item / op_val)),
-                ("/", true)  => Ok(Box::new(move |_| 1)),
+                ("+", false) => Ok(Box::new(move |item| item.checked_add(op_val))),
+                ("+", true)  => Ok(Box::new(move |item| item.checked_add(item))),
+                ("*", false) => Ok(Box::new(move |item| item.checked_mul(op_val))),
+                ("*", true)  => Ok(Box::new(move |item| item.checked_mul(item))),
+                ("-", false) => Ok(Box::new(move |item| item.checked_sub(op_val))),
+                ("-", true)  => Ok(Box::new(move |_| Some(0))),
+                ("/", false) => Ok(Box::new(move |item| item.checked_div(op_val))),
+                ("/", true)  => Ok(Box::new(move |_| Some(1))),
                 _ => Err(MonkeyParseError("Invalid or undefined operation"))
             }
         } else {
-            return Err(MonkeyParseError("Invalid op section line"));
+            Err(MonkeyParseError("Invalid op section line"))
         }
     }
     
-    fn parse_test(s: &str) -> Result<(Box<dyn Fn(usize) -> bool>, usize), MonkeyParseError> {
+    fn parse_test(s: &str) -> Result<(MonkeyTest, usize), MonkeyParseError> {
         let sections = s.split(':').collect::<Vec<&str>>();
         if sections.len() != 2 {
             return Err(MonkeyParseError("Invalid operation line"));
@@ -181,7 +201,7 @@ impl Monkey {
         let test_num = 
             sections[1]
                 .split(' ')
-                .last()
+                .next_back()
                 .unwrap()
                 .parse::<usize>()
                 .map_err(|_| MonkeyParseError("Invalid test number"))?;
@@ -192,16 +212,16 @@ impl Monkey {
         let target_val = 
             s
                 .split(' ')
-                .last()
+                .next_back()
                 .map(|s| s.parse::<usize>().unwrap_or(usize::MAX))
                 .unwrap();
         target_val
     }
     
-    pub fn execute_turn(&self, reduce: bool, modulus: usize) -> Vec<MonkeyTransfer> {
+    pub fn execute_turn(&self, reduce: bool, modulus: usize) -> Option<Vec<MonkeyTransfer>> {
         let mut transfers = Vec::new();
         for item in self.items.iter() {
-            let mut inspect_result = (self.operation)(*item);
+            let mut inspect_result = (self.operation)(*item)?;
             if reduce {
                 inspect_result /= 3;
             } else {
@@ -215,11 +235,12 @@ impl Monkey {
                 target: if check { self.true_monkey } else { self.false_monkey }
             })
         }
-        transfers
+        Some(transfers)
     }
 }
 
 custom_error!(MonkeyParseError);
+custom_error!(KeepAwayError);
 
 struct MonkeyTransfer {
     item: usize,
@@ -264,10 +285,15 @@ Monkey 3:
     
         let mut keepaway = KeepAway::new_from_str(input_str).expect("Unable to parse");
         assert_eq!(keepaway.monkeys.len(), 4);
-        keepaway.run_n_rounds(20, true);
+        keepaway.run_n_rounds(20, true).unwrap();
         assert_eq!(keepaway.calculate_monkey_business(), 10605);
         keepaway.reload().unwrap();
-        keepaway.run_n_rounds(10000, false);
+        keepaway.run_n_rounds(10000, false).unwrap();
         assert_eq!(keepaway.calculate_monkey_business(), 2713310158);
+
+        // Part 1's worry levels only stay small for the puzzle's twenty rounds.
+        keepaway.reload().unwrap();
+        keepaway.params_mut().unwrap().set("part_1_rounds", "1000").unwrap();
+        assert!(keepaway.try_part_1().is_err());
     }
 }
\ No newline at end of file
@@ -2,7 +2,7 @@ use std::{collections::HashSet, str::FromStr};
 
 use crate::util::{parse_each_char, read_input_to_str, str_to_grid_info, AOCSolution};
 
-solution!(Day 12 => HeightMap);
+solution!(Year 2022 Day 12 => HeightMap);
 
 pub struct HeightMap {
     tiles: Vec<MapTile>,
@@ -26,6 +26,7 @@ impl AOCSolution for HeightMap {
     }
 
     fn part_2(&mut self) -> String {
+        self.initialize();
         format!("{}", self.get_shortest_path_to(TileType::Start))
     }
 }
@@ -101,7 +102,7 @@ impl HeightMap {
                 }
                 false
             })
-            .map(|c| *c)
+            .copied()
             .collect::<Vec<(usize, usize)>>()
     }
 
@@ -112,7 +113,8 @@ impl HeightMap {
             TileType::Start => vec![vec![self.get_end_tile()]],
             _ => vec![vec![self.get_start_tile()]],
         };
-        while paths.len() > 0 {
+        while !paths.is_empty() {
+            trace_event!(Debug, "frontier", steps = paths[0].len() - 1, size = paths.len(), seen = coords_seen.len());
             let mut next_paths = Vec::new();
             for path in paths.iter() {
                 let last_tile = path.last().unwrap();
@@ -125,6 +127,7 @@ impl HeightMap {
                         _ => new_tile.height == 'a' as usize,
                     };
                     if check {
+                        trace_event!(Info, "path_found", length = path.len(), seen = coords_seen.len());
                         return path.len();
                     }
                     let mut new_path = path.clone();
@@ -175,8 +178,8 @@ impl FromStr for MapTile {
             _ => TileType::Mid,
         };
         let height = match s {
-            "S" => 'a' as u8,
-            "E" => 'z' as u8,
+            "S" => b'a',
+            "E" => b'z',
             _ => s.chars().last().unwrap() as u8,
         } as usize;
         Ok(Self {
@@ -1,10 +1,9 @@
 use serde::Deserialize;
-use serde_json;
 use std::str::FromStr;
 
 use crate::util::{convert_str_to_sections, parse_lines_into, read_input_to_str, AOCSolution};
 
-solution!(Day 13 => PacketReceiver);
+solution!(Year 2022 Day 13 => PacketReceiver);
 
 #[derive(Debug)]
 pub struct PacketReceiver {
@@ -78,8 +77,7 @@ impl PacketReceiver {
     fn get_all_packets(&self) -> Vec<PacketValue> {
         self.packet_pairs
             .iter()
-            .map(|p| vec![p.left.clone(), p.right.clone()])
-            .flatten()
+            .flat_map(|p| vec![p.left.clone(), p.right.clone()])
             .collect::<Vec<PacketValue>>()
     }
 
@@ -133,36 +131,34 @@ custom_error!(PacketValueParseError);
 
 impl PartialOrd for PacketValue {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketValue {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match (self, other) {
-            (PacketValue::Int(i), PacketValue::Int(j)) => i.partial_cmp(j),
+            (PacketValue::Int(i), PacketValue::Int(j)) => i.cmp(j),
             (PacketValue::Int(_), PacketValue::List(_)) => {
-                self.transmute().unwrap().partial_cmp(other)
+                self.transmute().unwrap().cmp(other)
             }
             (PacketValue::List(_), PacketValue::Int(_)) => {
-                self.partial_cmp(&other.transmute().unwrap())
+                self.cmp(&other.transmute().unwrap())
             }
             (PacketValue::List(l1), PacketValue::List(l2)) => {
                 let pairs = l1.iter().zip(l2.iter());
                 for pair in pairs {
-                    if let Some(ord) = pair.0.partial_cmp(&pair.1) {
-                        if ord == std::cmp::Ordering::Equal {
-                            continue;
-                        }
-                        return Some(ord);
+                    let ord = pair.0.cmp(pair.1);
+                    if ord != std::cmp::Ordering::Equal {
+                        return ord;
                     }
                 }
-                l1.len().partial_cmp(&l2.len())
+                l1.len().cmp(&l2.len())
             }
         }
     }
 }
 
-impl Ord for PacketValue {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-
 impl PacketValue {
     fn transmute(&self) -> Option<Self> {
         match self {
@@ -1,14 +1,20 @@
-use crate::util::{AOCSolution, SResult, parse_lines_into, read_input_to_str};
+use crate::util::{AOCSolution, PuzzleParams, SResult, parse_lines_into, read_input_to_str};
 use std::{collections::HashSet, str::FromStr};
 
-solution!(Day 14 => Cave);
+solution!(Year 2022 Day 14 => Cave);
+
+params!(CaveParams {
+    origin_x: usize = 500,
+    origin_y: usize = 0,
+});
 
 #[derive(Debug)]
 pub struct Cave {
     blocked: HashSet<(usize, usize)>,
     sand: Vec<Sand>,
     max_y: usize,
-    floor: usize
+    floor: usize,
+    params: CaveParams,
 }
 
 impl AOCSolution for Cave {
@@ -19,14 +25,28 @@ impl AOCSolution for Cave {
     }
     
     fn part_1(&mut self) -> String {
-        self.run_until_complete(false);
-        format!("{}", self.count_grains())
+        self.try_part_1().unwrap_or_else(|e| e.to_string())
     }
     
     fn part_2(&mut self) -> String {
+        self.try_part_2().unwrap_or_else(|e| e.to_string())
+    }
+
+    fn try_part_1(&mut self) -> SResult<String> {
+        self.check_origin()?;
+        self.run_until_complete(false);
+        Ok(format!("{}", self.count_grains()))
+    }
+
+    fn try_part_2(&mut self) -> SResult<String> {
+        self.check_origin()?;
         self.reset();
         self.run_until_complete(true);
-        format!("{}", self.count_grains())
+        Ok(format!("{}", self.count_grains()))
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
     }
 }
 
@@ -48,10 +68,28 @@ impl Cave {
             blocked,
             sand: Vec::new(),
             max_y,
-            floor: 2 + max_y
+            floor: 2 + max_y,
+            params: CaveParams::default(),
         })
     }
     
+    // The origin can be overridden, and the simulation only ends if sand can fall from it and come to rest.
+    // Sand spreads at most one column sideways for each row it falls, so it never reaches x = 0 from far
+    // enough right.
+    fn check_origin(&self) -> Result<(), CaveError> {
+        let origin = (self.params.origin_x, self.params.origin_y);
+        if origin.1 >= self.floor {
+            return Err(CaveError("The sand's origin must be above the floor"));
+        }
+        if origin.0 < self.floor {
+            return Err(CaveError("The sand's origin is too close to the cave's left edge"));
+        }
+        if self.blocked.contains(&origin) {
+            return Err(CaveError("The sand's origin is inside rock"));
+        }
+        Ok(())
+    }
+
     pub fn run_until_complete(&mut self, with_floor: bool) {
         if !with_floor {
             while self.simulate_sand_fall() {}
@@ -62,7 +100,7 @@ impl Cave {
     
     pub fn simulate_sand_fall(&mut self) -> bool {
         // Returns false if grain falls off map
-        let mut grain = Sand::new(500, 0);
+        let mut grain = Sand::new(self.params.origin_x, self.params.origin_y);
         while grain.did_move(&self.blocked) {
             if grain.position().1 > self.max_y {
                 return false;
@@ -70,16 +108,18 @@ impl Cave {
         }
         self.sand.push(grain.clone());
         self.blocked.insert(grain.position());
-        return true;
+        trace_event!(Debug, "grain_rest", at = grain.position(), grains = self.sand.len());
+        true
     }
     
     pub fn simulate_sand_fall_floor(&mut self) -> bool {
         // Returns false once grain stops at origin
-        let mut grain = Sand::new(500, 0);
+        let mut grain = Sand::new(self.params.origin_x, self.params.origin_y);
         while grain.did_move_with_floor(&self.blocked, self.floor) {}
         self.sand.push(grain.clone());
         self.blocked.insert(grain.position());
-        grain.position() != (500, 0)
+        trace_event!(Debug, "grain_rest", at = grain.position(), grains = self.sand.len(), floor = true);
+        grain.position() != (self.params.origin_x, self.params.origin_y)
     }
     
     pub fn count_grains(&self) -> usize {
@@ -121,6 +161,7 @@ impl FromStr for Rock {
 }
 
 custom_error!(RockParseError);
+custom_error!(CaveError);
 
 impl Rock {
     fn parse_vertices(s: &str) -> Result<Vec<(usize, usize)>, RockParseError> {
@@ -139,7 +180,7 @@ impl Rock {
     
     pub fn populate_blocked(&self, map: &mut HashSet<(usize, usize)>) {
         for cell in self.cells.iter() {
-            map.insert(cell.clone());
+            map.insert(*cell);
         }
     }
     
@@ -186,7 +227,7 @@ impl Sand {
     }
     
     pub fn position(&self) -> (usize, usize) {
-        self.cell.clone()
+        self.cell
     }
 }
 
@@ -204,5 +245,11 @@ mod test {
         let mut cave = Cave::new_from_str(input_str).expect("Failed to parse rocks");
         assert_eq!(cave.part_1(), "24");
         assert_eq!(cave.part_2(), "93");
+
+        for (x, y) in [("500", "20"), ("5", "0"), ("498", "5")] {
+            cave.params_mut().unwrap().set("origin_x", x).unwrap();
+            cave.params_mut().unwrap().set("origin_y", y).unwrap();
+            assert!(cave.try_part_1().is_err() && cave.try_part_2().is_err());
+        }
     }
 }
\ No newline at end of file
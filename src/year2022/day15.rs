@@ -1,10 +1,16 @@
-use crate::{util::{AOCSolution, parse_lines_into, read_input_to_str}, SResult};
+use crate::{util::{AOCSolution, PuzzleParams, parse_lines_into, read_input_to_str}, SResult};
 use std::str::FromStr;
 
-solution!(Day 15 => SignalMap);
+solution!(Year 2022 Day 15 => SignalMap);
+
+params!(SignalMapParams {
+    target_row: i32 = 2000000 => -1_000_000_000..=1_000_000_000,
+    search_bound: i32 = 4000000 => 0..=1_000_000_000,
+});
 
 pub struct SignalMap {
     readings: Vec<Reading>,
+    params: SignalMapParams,
 }
 
 impl AOCSolution for SignalMap {
@@ -15,14 +21,17 @@ impl AOCSolution for SignalMap {
     }
     
     fn part_1(&mut self) -> String {
-        format!("{}", self.count_impossible_cells_in_row(2000000))
+        format!("{}", self.count_impossible_cells_in_row(self.params.target_row))
     }
     
     fn part_2(&mut self) -> String {
-        format!("{:?}", self.get_tuning_freq(0, 4000000, 0, 4000000))
+        let bound = self.params.search_bound;
+        format!("{:?}", self.get_tuning_freq(0, bound, 0, bound))
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
     }
-    
-    
 }
 
 impl SignalMap {
@@ -35,6 +44,7 @@ impl SignalMap {
         let readings = parse_lines_into::<Reading>(input_str)?;
         Ok(Self {
             readings,
+            params: SignalMapParams::default(),
         })
     }
     
@@ -70,7 +80,7 @@ impl SignalMap {
                     }
                 }
             }
-            if new_coll.len() == 0 { return None; }
+            if new_coll.is_empty() { return None; }
             current_coll = new_coll;
         }
         
@@ -80,15 +90,14 @@ impl SignalMap {
     fn count_impossible_cells_in_row(&self, row: i32) -> usize {
         let mut invalid_ranges = self.readings
             .iter()
-            .map(|r| r.get_invalid_cells_in_row_range(row))
-            .flatten()
+            .flat_map(|r| r.get_invalid_cells_in_row_range(row))
             .collect::<Vec<CellRange>>();
             
         invalid_ranges.sort_unstable();
         let mut res: Vec<CellRange> = Vec::new();
         for range in invalid_ranges.iter() {
             if let Some(r) = res.pop() {
-                let u = r.union(&range);
+                let u = r.union(range);
                 res.extend(u)
             } else {
                 res.push(range.clone())
@@ -226,15 +235,15 @@ impl CellRange {
 struct Sensor(i32, i32);
 struct Beacon(i32, i32);
 
-impl Into<(i32, i32)> for Sensor {
-    fn into(self) -> (i32, i32) {
-        (self.0, self.1)
+impl From<Sensor> for (i32, i32) {
+    fn from(sensor: Sensor) -> Self {
+        (sensor.0, sensor.1)
     }
 }
 
-impl Into<(i32, i32)> for Beacon {
-    fn into(self) -> (i32, i32) {
-        (self.0, self.1)
+impl From<Beacon> for (i32, i32) {
+    fn from(beacon: Beacon) -> Self {
+        (beacon.0, beacon.1)
     }
 }
 
@@ -0,0 +1,244 @@
+use crate::util::{parse_lines_into, read_input_to_str, AOCSolution, PuzzleParams, SResult};
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+solution!(Year 2022 Day 16 => ValveNetwork);
+
+params!(ValveNetworkParams {
+    start_valve: String = "AA".to_string(),
+    solo_minutes: u32 = 30 => ..=1000,
+    team_minutes: u32 = 26 => ..=1000,
+});
+
+pub struct ValveNetwork {
+    valves: Vec<Valve>,
+    params: ValveNetworkParams,
+}
+
+impl AOCSolution for ValveNetwork {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        self.try_part_1().unwrap_or_else(|e| e.to_string())
+    }
+
+    fn part_2(&mut self) -> String {
+        self.try_part_2().unwrap_or_else(|e| e.to_string())
+    }
+
+    fn try_part_1(&mut self) -> SResult<String> {
+        let best = self.best_pressure_per_valve_set(self.params.solo_minutes)?;
+        Ok(format!("{}", best.values().max().unwrap_or(&0)))
+    }
+
+    fn try_part_2(&mut self) -> SResult<String> {
+        Ok(format!("{}", self.best_pressure_with_helper(self.params.team_minutes)?))
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
+}
+
+impl ValveNetwork {
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let valves = parse_lines_into::<Valve>(input_str)?;
+        Ok(Self {
+            valves,
+            params: ValveNetworkParams::default(),
+        })
+    }
+
+    // Best pressure release for every set of opened valves reachable in the time limit.
+    // Sets are bitmasks over the valves with a nonzero flow rate.
+    fn best_pressure_per_valve_set(&self, minutes: u32) -> Result<HashMap<u64, u32>, ValveNetworkError> {
+        let network = self.compress()?;
+        let mut best = HashMap::new();
+        network.explore(network.start, minutes, 0, 0, &mut best);
+        Ok(best)
+    }
+
+    // Both explorers open disjoint sets of valves, so pair up the best disjoint sets.
+    fn best_pressure_with_helper(&self, minutes: u32) -> Result<u32, ValveNetworkError> {
+        let best = self
+            .best_pressure_per_valve_set(minutes)?
+            .into_iter()
+            .collect::<Vec<(u64, u32)>>();
+        let mut result = 0;
+        for (idx, (mine, my_pressure)) in best.iter().enumerate() {
+            for (theirs, their_pressure) in best.iter().skip(idx) {
+                if mine & theirs == 0 {
+                    result = u32::max(result, my_pressure + their_pressure);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    // Reduces the network to the start valve plus the valves worth opening,
+    // with the travel time between each pair of them.
+    fn compress(&self) -> Result<CompressedNetwork, ValveNetworkError> {
+        let indices = self
+            .valves
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| (v.name.as_str(), idx))
+            .collect::<HashMap<&str, usize>>();
+        let start = indices
+            .get(self.params.start_valve.as_str())
+            .copied()
+            .ok_or(ValveNetworkError("The start valve isn't in the network"))?;
+        let mut targets = self
+            .valves
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.flow_rate > 0)
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>();
+        targets.push(start);
+
+        let distances = targets
+            .iter()
+            .map(|source| {
+                let from_source = self.distances_from(*source, &indices);
+                targets.iter().map(|t| from_source[*t]).collect::<Vec<u32>>()
+            })
+            .collect::<Vec<Vec<u32>>>();
+        let flow_rates = targets
+            .iter()
+            .map(|t| self.valves[*t].flow_rate)
+            .collect::<Vec<u32>>();
+
+        Ok(CompressedNetwork {
+            start: targets.len() - 1,
+            flow_rates,
+            distances,
+        })
+    }
+
+    fn distances_from(&self, source: usize, indices: &HashMap<&str, usize>) -> Vec<u32> {
+        let mut distances = vec![u32::MAX; self.valves.len()];
+        let mut queue = VecDeque::new();
+        distances[source] = 0;
+        queue.push_back(source);
+        while let Some(current) = queue.pop_front() {
+            for tunnel in self.valves[current].tunnels.iter() {
+                if let Some(&next) = indices.get(tunnel.as_str()) {
+                    if distances[next] == u32::MAX {
+                        distances[next] = distances[current] + 1;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        distances
+    }
+}
+
+struct CompressedNetwork {
+    start: usize,
+    flow_rates: Vec<u32>,
+    distances: Vec<Vec<u32>>,
+}
+
+impl CompressedNetwork {
+    fn explore(&self, position: usize, time_left: u32, opened: u64, pressure: u32, best: &mut HashMap<u64, u32>) {
+        let entry = best.entry(opened).or_insert(0);
+        *entry = u32::max(*entry, pressure);
+        for next in 0..self.start {
+            if opened & (1 << next) != 0 {
+                continue;
+            }
+            let cost = self.distances[position][next].saturating_add(1);
+            if cost >= time_left {
+                continue;
+            }
+            let remaining = time_left - cost;
+            self.explore(
+                next,
+                remaining,
+                opened | (1 << next),
+                pressure + remaining * self.flow_rates[next],
+                best,
+            );
+        }
+    }
+}
+
+struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ValveParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (valve, tunnels) = s
+            .split_once("; ")
+            .ok_or(ValveParseError("Invalid valve line, expected valve and tunnel sections"))?;
+        let name = valve
+            .split(' ')
+            .nth(1)
+            .ok_or(ValveParseError("Missing valve name"))?
+            .to_string();
+        let flow_rate = valve
+            .split('=')
+            .nth(1)
+            .and_then(|r| r.parse::<u32>().ok())
+            .ok_or(ValveParseError("Invalid flow rate"))?;
+        let tunnels = tunnels
+            .split_whitespace()
+            .skip(4) // "tunnels lead to valves"
+            .map(|t| t.trim_end_matches(',').to_string())
+            .collect::<Vec<String>>();
+        Ok(Self {
+            name,
+            flow_rate,
+            tunnels,
+        })
+    }
+}
+
+custom_error!(ValveParseError);
+custom_error!(ValveNetworkError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
+
+        let mut network = ValveNetwork::new_from_str(input_str).expect("Failed to parse valves");
+        assert_eq!(network.valves.len(), 10);
+        assert_eq!(network.part_1(), "1651");
+        assert_eq!(network.part_2(), "1707");
+        network.params_mut().unwrap().set("start_valve", "ZZ").unwrap();
+        let missing = network.try_part_1().expect_err("Solved without a start valve");
+        assert_eq!(missing.to_string(), "The start valve isn't in the network");
+        assert!(network.try_part_2().is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::util::{read_input_to_str, AOCSolution, PuzzleParams, SResult};
+use std::collections::HashMap;
+
+solution!(Year 2022 Day 17 => Chamber);
+
+params!(ChamberParams {
+    part_1_rocks: u64 = 2022,
+    part_2_rocks: u64 = 1000000000000,
+});
+
+pub struct Chamber {
+    jets: Vec<Jet>,
+    params: ChamberParams,
+}
+
+impl AOCSolution for Chamber {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        format!("{}", self.tower_height(self.params.part_1_rocks))
+    }
+
+    fn part_2(&mut self) -> String {
+        format!("{}", self.tower_height(self.params.part_2_rocks))
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
+}
+
+impl Chamber {
+    // Rows of each rock from the bottom up, as 7-bit masks with the leftmost column in the highest bit.
+    // Every rock starts two units away from the left wall.
+    const ROCKS: [&'static [u8]; 5] = [
+        &[0b0011110],
+        &[0b0001000, 0b0011100, 0b0001000],
+        &[0b0011100, 0b0000100, 0b0000100],
+        &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+        &[0b0011000, 0b0011000],
+    ];
+    // Enough of the top of the tower to recognise a repeating state.
+    const PROFILE_DEPTH: usize = 32;
+
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let mut jets = Vec::new();
+        for c in input_str.trim().chars() {
+            match c {
+                '<' => jets.push(Jet::Left),
+                '>' => jets.push(Jet::Right),
+                _ => return Err(Box::new(JetParseError("Invalid jet, expected '<' or '>'"))),
+            }
+        }
+        if jets.is_empty() {
+            return Err(Box::new(JetParseError("No jets in the input")));
+        }
+        Ok(Self {
+            jets,
+            params: ChamberParams::default(),
+        })
+    }
+
+    pub fn tower_height(&self, rocks: u64) -> u64 {
+        let mut tower = Tower::new();
+        let mut seen: HashMap<(usize, usize, Vec<u8>), (u64, u64)> = HashMap::new();
+        let mut skipped_height = 0;
+        let mut dropped = 0;
+        while dropped < rocks {
+            let rock_idx = (dropped % Self::ROCKS.len() as u64) as usize;
+            tower.drop_rock(Self::ROCKS[rock_idx], &self.jets);
+            dropped += 1;
+
+            // Once the same rock, jet and tower surface come around again, the tower
+            // grows by the same amount every cycle, so skip as many cycles as fit.
+            if skipped_height == 0 {
+                let key = (rock_idx, tower.jet_idx, tower.profile(Self::PROFILE_DEPTH));
+                let height = tower.height() as u64;
+                if let Some((prev_dropped, prev_height)) = seen.get(&key) {
+                    let cycle_len = dropped - prev_dropped;
+                    let cycles = (rocks - dropped) / cycle_len;
+                    skipped_height = cycles * (height - prev_height);
+                    dropped += cycles * cycle_len;
+                } else {
+                    seen.insert(key, (dropped, height));
+                }
+            }
+        }
+        tower.height() as u64 + skipped_height
+    }
+}
+
+struct Tower {
+    rows: Vec<u8>,
+    jet_idx: usize,
+}
+
+impl Tower {
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            jet_idx: 0,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn drop_rock(&mut self, shape: &[u8], jets: &[Jet]) {
+        let mut rock = shape.to_vec();
+        let mut y = self.height() + 3;
+        loop {
+            let jet = &jets[self.jet_idx];
+            self.jet_idx = (self.jet_idx + 1) % jets.len();
+            if let Some(pushed) = Self::push(&rock, jet) {
+                if !self.collides(&pushed, y) {
+                    rock = pushed;
+                }
+            }
+            if y == 0 || self.collides(&rock, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+        self.rest(&rock, y);
+    }
+
+    fn push(rock: &[u8], jet: &Jet) -> Option<Vec<u8>> {
+        match jet {
+            Jet::Left if rock.iter().all(|row| row & 0b1000000 == 0) => {
+                Some(rock.iter().map(|row| row << 1).collect())
+            }
+            Jet::Right if rock.iter().all(|row| row & 0b0000001 == 0) => {
+                Some(rock.iter().map(|row| row >> 1).collect())
+            }
+            _ => None,
+        }
+    }
+
+    fn collides(&self, rock: &[u8], y: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(dy, row)| self.rows.get(y + dy).map(|r| r & row != 0).unwrap_or(false))
+    }
+
+    fn rest(&mut self, rock: &[u8], y: usize) {
+        for (dy, row) in rock.iter().enumerate() {
+            if self.rows.len() <= y + dy {
+                self.rows.push(0);
+            }
+            self.rows[y + dy] |= row;
+        }
+    }
+
+    fn profile(&self, depth: usize) -> Vec<u8> {
+        self.rows.iter().rev().take(depth).copied().collect()
+    }
+}
+
+enum Jet {
+    Left,
+    Right,
+}
+
+custom_error!(JetParseError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+        let mut chamber = Chamber::new_from_str(input_str).expect("Failed to parse jets");
+        assert_eq!(chamber.part_1(), "3068");
+        assert_eq!(chamber.part_2(), "1514285714288");
+        assert!(Chamber::new_from_str("\n").is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::util::{parse_lines_into, read_input_to_str, AOCSolution, SResult};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+solution!(Year 2022 Day 18 => LavaDroplet);
+
+pub struct LavaDroplet {
+    cubes: HashSet<Cube>,
+}
+
+impl AOCSolution for LavaDroplet {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        format!("{}", self.surface_area())
+    }
+
+    fn part_2(&mut self) -> String {
+        format!("{}", self.exterior_surface_area())
+    }
+}
+
+impl LavaDroplet {
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let cubes = parse_lines_into::<Cube>(input_str)?;
+        Ok(Self {
+            cubes: cubes.into_iter().collect(),
+        })
+    }
+
+    pub fn surface_area(&self) -> usize {
+        self.cubes
+            .iter()
+            .flat_map(|c| c.neighbors())
+            .filter(|n| !self.cubes.contains(n))
+            .count()
+    }
+
+    // Flood fills the air around the droplet from just outside its bounding box,
+    // counting every face the outside air touches.
+    pub fn exterior_surface_area(&self) -> usize {
+        let (min, max) = self.bounds();
+        let in_bounds = |c: &Cube| {
+            (min.0..=max.0).contains(&c.0) && (min.1..=max.1).contains(&c.1) && (min.2..=max.2).contains(&c.2)
+        };
+
+        let mut outside = HashSet::new();
+        let mut frontier = vec![min];
+        outside.insert(min);
+        let mut faces = 0;
+        while let Some(air) = frontier.pop() {
+            for neighbor in air.neighbors() {
+                if self.cubes.contains(&neighbor) {
+                    faces += 1;
+                } else if in_bounds(&neighbor) && outside.insert(neighbor) {
+                    frontier.push(neighbor);
+                }
+            }
+        }
+        faces
+    }
+
+    fn bounds(&self) -> (Cube, Cube) {
+        let mut min = Cube(i32::MAX, i32::MAX, i32::MAX);
+        let mut max = Cube(i32::MIN, i32::MIN, i32::MIN);
+        for cube in self.cubes.iter() {
+            min = Cube(min.0.min(cube.0), min.1.min(cube.1), min.2.min(cube.2));
+            max = Cube(max.0.max(cube.0), max.1.max(cube.1), max.2.max(cube.2));
+        }
+        // Pad by one so the air can flow all the way around.
+        (
+            Cube(min.0 - 1, min.1 - 1, min.2 - 1),
+            Cube(max.0 + 1, max.1 + 1, max.2 + 1),
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct Cube(i32, i32, i32);
+
+impl Cube {
+    pub fn neighbors(&self) -> [Cube; 6] {
+        [
+            Cube(self.0 + 1, self.1, self.2),
+            Cube(self.0 - 1, self.1, self.2),
+            Cube(self.0, self.1 + 1, self.2),
+            Cube(self.0, self.1 - 1, self.2),
+            Cube(self.0, self.1, self.2 + 1),
+            Cube(self.0, self.1, self.2 - 1),
+        ]
+    }
+}
+
+impl FromStr for Cube {
+    type Err = CubeParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords: [&str; 3] = s
+            .split(',')
+            .collect::<Vec<&str>>()
+            .try_into()
+            .map_err(|_| CubeParseError("Invalid cube, expected X,Y,Z"))?;
+        let mut parsed = [0; 3];
+        for (idx, coord) in coords.iter().enumerate() {
+            parsed[idx] = coord
+                .parse::<i32>()
+                .map_err(|_| CubeParseError("Invalid coordinate"))?;
+        }
+        Ok(Self(parsed[0], parsed[1], parsed[2]))
+    }
+}
+
+custom_error!(CubeParseError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+        2,2,2
+        1,2,2
+        3,2,2
+        2,1,2
+        2,3,2
+        2,2,1
+        2,2,3
+        2,2,4
+        2,2,6
+        1,2,5
+        3,2,5
+        2,1,5
+        2,3,5
+        ";
+
+        let mut droplet = LavaDroplet::new_from_str(input_str).expect("Failed to parse cubes");
+        assert_eq!(droplet.part_1(), "64");
+        assert_eq!(droplet.part_2(), "58");
+    }
+}
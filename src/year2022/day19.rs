@@ -0,0 +1,195 @@
+use crate::util::{parse_lines_into, read_input_to_str, AOCSolution, PuzzleParams, SResult};
+use std::str::FromStr;
+
+solution!(Year 2022 Day 19 => RobotFactory);
+
+params!(RobotFactoryParams {
+    part_1_minutes: u32 = 24 => ..=1000,
+    part_2_minutes: u32 = 32 => ..=1000,
+    part_2_blueprints: usize = 3,
+});
+
+pub struct RobotFactory {
+    blueprints: Vec<Blueprint>,
+    params: RobotFactoryParams,
+}
+
+impl AOCSolution for RobotFactory {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        format!("{}", self.quality_level_sum(self.params.part_1_minutes))
+    }
+
+    fn part_2(&mut self) -> String {
+        let geodes: u32 = self
+            .blueprints
+            .iter()
+            .take(self.params.part_2_blueprints)
+            .map(|b| b.max_geodes(self.params.part_2_minutes))
+            .product();
+        format!("{}", geodes)
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
+}
+
+impl RobotFactory {
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let blueprints = parse_lines_into::<Blueprint>(input_str)?;
+        Ok(Self {
+            blueprints,
+            params: RobotFactoryParams::default(),
+        })
+    }
+
+    pub fn quality_level_sum(&self, minutes: u32) -> u32 {
+        self.blueprints
+            .iter()
+            .map(|b| b.id * b.max_geodes(minutes))
+            .sum()
+    }
+}
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+struct Blueprint {
+    id: u32,
+    // costs[robot][resource]
+    costs: [[u32; 4]; 4],
+}
+
+impl Blueprint {
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        let mut limits = [u32::MAX; 4];
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            limits[resource] = self.costs.iter().map(|c| c[resource]).max().unwrap_or(0);
+        }
+        let start = FactoryState {
+            time_left: minutes,
+            robots: [1, 0, 0, 0],
+            resources: [0; 4],
+        };
+        let mut best = 0;
+        self.search(&start, &limits, &mut best);
+        best
+    }
+
+    // Rather than stepping minute by minute, pick the next robot to build and
+    // wait however long it takes to afford it.
+    fn search(&self, state: &FactoryState, limits: &[u32; 4], best: &mut u32) {
+        let idle_geodes = state.resources[GEODE] + state.robots[GEODE] * state.time_left;
+        *best = u32::max(*best, idle_geodes);
+
+        // Even building a geode robot every remaining minute can't beat the best.
+        let t = state.time_left;
+        if idle_geodes + t * t.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        for robot in (ORE..=GEODE).rev() {
+            if state.robots[robot] >= limits[robot] {
+                continue;
+            }
+            if let Some(next) = state.build(robot, &self.costs[robot]) {
+                self.search(&next, limits, best);
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct FactoryState {
+    time_left: u32,
+    robots: [u32; 4],
+    resources: [u32; 4],
+}
+
+impl FactoryState {
+    fn build(&self, robot: usize, cost: &[u32; 4]) -> Option<FactoryState> {
+        let mut wait = 0;
+        for (resource, amount) in cost.iter().enumerate() {
+            if *amount <= self.resources[resource] {
+                continue;
+            }
+            if self.robots[resource] == 0 {
+                return None;
+            }
+            let missing = amount - self.resources[resource];
+            wait = u32::max(wait, missing.div_ceil(self.robots[resource]));
+        }
+        // Building takes a minute, and the robot must finish before time runs out to be useful.
+        if wait + 1 >= self.time_left {
+            return None;
+        }
+        let elapsed = wait + 1;
+        let mut next = self.clone();
+        next.time_left -= elapsed;
+        for (resource, amount) in cost.iter().enumerate() {
+            next.resources[resource] += self.robots[resource] * elapsed;
+            next.resources[resource] -= amount;
+        }
+        next.robots[robot] += 1;
+        Some(next)
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = BlueprintParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = s
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|n| !n.is_empty())
+            .map(|n| n.parse::<u32>().map_err(|_| BlueprintParseError("Invalid number")))
+            .collect::<Result<Vec<u32>, BlueprintParseError>>()?;
+        if numbers.len() != 7 {
+            return Err(BlueprintParseError("Invalid blueprint, expected an id and six costs"));
+        }
+        let mut costs = [[0; 4]; 4];
+        costs[ORE][ORE] = numbers[1];
+        costs[CLAY][ORE] = numbers[2];
+        costs[OBSIDIAN][ORE] = numbers[3];
+        costs[OBSIDIAN][CLAY] = numbers[4];
+        costs[GEODE][ORE] = numbers[5];
+        costs[GEODE][OBSIDIAN] = numbers[6];
+        Ok(Self {
+            id: numbers[0],
+            costs,
+        })
+    }
+}
+
+custom_error!(BlueprintParseError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
+";
+
+        let mut factory = RobotFactory::new_from_str(input_str).expect("Failed to parse blueprints");
+        assert_eq!(factory.part_1(), "33");
+        assert_eq!(factory.blueprints[0].max_geodes(32), 56);
+        assert_eq!(factory.blueprints[1].max_geodes(32), 62);
+    }
+}
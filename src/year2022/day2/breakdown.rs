@@ -0,0 +1,169 @@
+use super::encodings::*;
+use super::RPSStrategyGuide;
+use std::fmt::Write;
+
+// One round of a guide as read by some encoding, with its score split into what the hand played
+// earns and what the outcome earns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundBreakdown {
+    pub opponent: Hand,
+    pub player: Hand,
+    pub outcome: Outcome,
+    pub hand_score: u32,
+    pub outcome_score: u32,
+    // The guide's score up to and including this round.
+    pub running_total: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuideBreakdown {
+    pub rounds: Vec<RoundBreakdown>,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    // How often the player plays each hand, indexed by hand.
+    pub hand_counts: Vec<usize>,
+    pub hand_score: u32,
+    pub outcome_score: u32,
+    pub total: u32,
+}
+
+// Two readings of the same guide, round for round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingComparison {
+    pub names: [String; 2],
+    pub breakdowns: [GuideBreakdown; 2],
+}
+
+impl RPSStrategyGuide {
+    pub fn breakdown(&self, encoding: &dyn InstructionParsingStrategy) -> GuideBreakdown {
+        let rules = self.rules();
+        let mut breakdown = GuideBreakdown {
+            rounds: Vec::new(),
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            hand_counts: vec![0; rules.len()],
+            hand_score: 0,
+            outcome_score: 0,
+            total: 0,
+        };
+        for round in self.rounds(encoding) {
+            let outcome = rules.versus(round.player, round.opponent);
+            let hand_score = rules.hand_score(round.player);
+            let outcome_score = rules.outcome_score(outcome);
+            match outcome {
+                Outcome::Win => breakdown.wins += 1,
+                Outcome::Draw => breakdown.draws += 1,
+                Outcome::Loss => breakdown.losses += 1,
+            }
+            breakdown.hand_counts[round.player.index()] += 1;
+            breakdown.hand_score += hand_score;
+            breakdown.outcome_score += outcome_score;
+            breakdown.total += hand_score + outcome_score;
+            breakdown.rounds.push(RoundBreakdown {
+                opponent: round.opponent,
+                player: round.player,
+                outcome,
+                hand_score,
+                outcome_score,
+                running_total: breakdown.total,
+            });
+        }
+        breakdown
+    }
+
+    // Names head the CSV columns, so they should be plain identifiers like `natural`.
+    pub fn compare_encodings(&self, encodings: [(&str, &dyn InstructionParsingStrategy); 2]) -> EncodingComparison {
+        EncodingComparison {
+            names: encodings.map(|(name, _)| name.to_string()),
+            breakdowns: encodings.map(|(_, encoding)| self.breakdown(encoding)),
+        }
+    }
+
+    // The puzzle's two readings: part 1's hands and part 2's outcomes.
+    pub fn compare_puzzle_encodings(&self) -> EncodingComparison {
+        self.compare_encodings([
+            ("natural", &NaturalEncodingStrategy {}),
+            ("loss_draw_win", &LossDrawWinEncodingStrategy {}),
+        ])
+    }
+
+    pub fn breakdown_report(&self) -> Option<String> {
+        let comparison = self.compare_puzzle_encodings();
+        let mut report = String::new();
+        writeln!(report, "Rounds: {}", self.instructions().len()).ok()?;
+        for (name, breakdown) in comparison.names.iter().zip(&comparison.breakdowns) {
+            writeln!(report, "{}:", name).ok()?;
+            writeln!(
+                report,
+                "  Total {} = {} from hands + {} from outcomes",
+                breakdown.total, breakdown.hand_score, breakdown.outcome_score
+            )
+            .ok()?;
+            writeln!(
+                report,
+                "  Won {}, drew {}, lost {}",
+                breakdown.wins, breakdown.draws, breakdown.losses
+            )
+            .ok()?;
+            let hands = self
+                .rules()
+                .hands()
+                .map(|hand| format!("{} {}", self.rules().name(hand), breakdown.hand_counts[hand.index()]))
+                .collect::<Vec<String>>();
+            writeln!(report, "  Played {}", hands.join(", ")).ok()?;
+        }
+        Some(report)
+    }
+}
+
+const ROUND_COLUMNS: [&str; 7] = [
+    "opponent", "player", "outcome", "hand_score", "outcome_score", "score", "running_total",
+];
+
+impl GuideBreakdown {
+    // One row per round, numbered from 1.
+    pub fn to_csv(&self, rules: &Rules) -> String {
+        let mut csv = format!("round,{}\n", ROUND_COLUMNS.join(","));
+        for (i, round) in self.rounds.iter().enumerate() {
+            // Writing to a String can't fail.
+            writeln!(csv, "{},{}", i + 1, round.csv_fields(rules)).unwrap();
+        }
+        csv
+    }
+}
+
+impl EncodingComparison {
+    // One row per round, with each encoding's columns prefixed by its name. Encodings can read the
+    // opponent's letters differently, so each gets its own opponent column.
+    pub fn to_csv(&self, rules: &Rules) -> String {
+        let mut csv = String::from("round");
+        for name in &self.names {
+            for column in ROUND_COLUMNS {
+                write!(csv, ",{}_{}", name, column).unwrap();
+            }
+        }
+        csv.push('\n');
+        let [left, right] = &self.breakdowns;
+        for (i, (l, r)) in left.rounds.iter().zip(&right.rounds).enumerate() {
+            writeln!(csv, "{},{},{}", i + 1, l.csv_fields(rules), r.csv_fields(rules)).unwrap();
+        }
+        csv
+    }
+}
+
+impl RoundBreakdown {
+    fn csv_fields(&self, rules: &Rules) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            rules.name(self.opponent),
+            rules.name(self.player),
+            self.outcome.name(),
+            self.hand_score,
+            self.outcome_score,
+            self.hand_score + self.outcome_score,
+            self.running_total
+        )
+    }
+}
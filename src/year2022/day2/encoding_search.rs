@@ -0,0 +1,113 @@
+use super::encodings::*;
+use super::RPSStrategyGuide;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EncodingSearchResult {
+    pub encoding: ConfiguredEncodingStrategy,
+    pub score: u32,
+}
+
+// Every reading that matches a claimed score, in search order. More than one means the score alone
+// can't tell them apart, e.g. when a letter never appears in the guide.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DecodedEncoding {
+    NoMatch,
+    Unique(ConfiguredEncodingStrategy),
+    Ambiguous(Vec<ConfiguredEncodingStrategy>),
+}
+
+impl RPSStrategyGuide {
+    // The best way to read the player's column, taking A, B and C as the first three hands. Every
+    // assignment of X, Y and Z to three different hands, or to the three outcomes, is tried.
+    pub fn best_player_encoding(&self) -> EncodingSearchResult {
+        let natural = [Hand::new(0), Hand::new(1), Hand::new(2)];
+        self.best_encoding_among(&[natural])
+    }
+
+    // As `best_player_encoding`, but A, B and C may also stand for any three different hands.
+    pub fn best_encoding(&self) -> EncodingSearchResult {
+        let opponents = injections(self.rules().len())
+            .into_iter()
+            .map(|hands| hands.map(Hand::new))
+            .collect::<Vec<[Hand; 3]>>();
+        self.best_encoding_among(&opponents)
+    }
+
+    // Works out which readings of the player's column could have produced a claimed total, taking
+    // A, B and C as the first three hands. Both hand and outcome readings are considered.
+    pub fn decode_player_encoding(&self, score: u32) -> DecodedEncoding {
+        let natural = [Hand::new(0), Hand::new(1), Hand::new(2)];
+        let mut matches = self
+            .scored_encodings(&[natural])
+            .filter(|result| result.score == score)
+            .map(|result| result.encoding)
+            .collect::<Vec<ConfiguredEncodingStrategy>>();
+        match matches.len() {
+            0 => DecodedEncoding::NoMatch,
+            1 => DecodedEncoding::Unique(matches.remove(0)),
+            _ => DecodedEncoding::Ambiguous(matches),
+        }
+    }
+
+    // Ties go to the mapping tried first: opponents in order, then player hands before outcomes,
+    // each in lexicographic order, so the puzzle's own readings win a tie.
+    fn best_encoding_among(&self, opponents: &[[Hand; 3]]) -> EncodingSearchResult {
+        let mut best: Option<EncodingSearchResult> = None;
+        for result in self.scored_encodings(opponents) {
+            if best.as_ref().is_none_or(|b| result.score > b.score) {
+                best = Some(result);
+            }
+        }
+        // The guide's rules always have at least three hands, so there's always something to try.
+        best.expect("No encodings to search.")
+    }
+
+    // Scores every player reading against each of `opponents`, in the order described above.
+    fn scored_encodings<'a>(&'a self, opponents: &'a [[Hand; 3]]) -> impl Iterator<Item = EncodingSearchResult> + 'a {
+        let rules = self.rules();
+        // Each distinct row and how often it comes up, so each reading only scores a handful of rows.
+        let mut rows: Vec<(EncodedInstruction, u32)> = Vec::new();
+        for inst in self.instructions() {
+            let inst = inst.for_player(0);
+            match rows.iter_mut().find(|(row, _)| *row == inst) {
+                Some((_, count)) => *count += 1,
+                None => rows.push((inst, 1)),
+            }
+        }
+
+        let outcomes = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+        let players = injections(rules.len())
+            .into_iter()
+            .map(|hands| hands.map(|h| PlayerMapping::Hand(Hand::new(h))))
+            .chain(injections(3).into_iter().map(|o| o.map(|i| PlayerMapping::Outcome(outcomes[i]))))
+            .collect::<Vec<[PlayerMapping; 3]>>();
+
+        opponents.iter().flat_map(move |opponent| {
+            let rows = rows.clone();
+            players.clone().into_iter().map(move |player| {
+                let encoding = ConfiguredEncodingStrategy::new(*opponent, player);
+                let score = rows
+                    .iter()
+                    .map(|(inst, count)| {
+                        let round = encoding.parse_encoded(inst, 0, rules).expect("Guides check every row against their rules");
+                        count * round.score(rules)
+                    })
+                    .sum();
+                EncodingSearchResult { encoding, score }
+            })
+        })
+    }
+}
+
+// Every way of picking three different indices below `n`, in lexicographic order.
+fn injections(n: usize) -> Vec<[usize; 3]> {
+    let mut picks = Vec::new();
+    for a in 0..n {
+        for b in (0..n).filter(|b| *b != a) {
+            for c in (0..n).filter(|c| *c != a && *c != b) {
+                picks.push([a, b, c]);
+            }
+        }
+    }
+    picks
+}
@@ -0,0 +1,122 @@
+use super::*;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+// An encoding read from a config rather than compiled in. Each letter is mapped by name to one of
+// the rules' hands, or for the player's column, to an outcome:
+//
+//     [opponent]          # optional, defaults to the first three hands in order
+//     A = "Rock"
+//     B = "Paper"
+//     C = "Scissors"
+//
+//     [player]
+//     X = "Loss"
+//     Y = "Paper"
+//     Z = "Win"
+//
+// Names are matched as in `PlayerMapping::named`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ConfiguredEncodingStrategy {
+    opponent: [Hand; 3],
+    player: [PlayerMapping; 3],
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EncodingFile {
+    opponent: Option<BTreeMap<String, String>>,
+    player: BTreeMap<String, String>,
+}
+
+impl ConfiguredEncodingStrategy {
+    pub fn new(opponent: [Hand; 3], player: [PlayerMapping; 3]) -> Self {
+        Self { opponent, player }
+    }
+
+    pub fn from_toml(input: &str, rules: &Rules) -> Result<Self, EncodingConfigError> {
+        let file: EncodingFile =
+            toml::from_str(input).map_err(|_| EncodingConfigError("Invalid encoding config, expected [opponent] and [player] tables."))?;
+
+        let opponent = match file.opponent {
+            Some(table) => {
+                let names = columns(&table, ["A", "B", "C"])?;
+                let mut hands = [Hand::new(0); 3];
+                for (hand, name) in hands.iter_mut().zip(names) {
+                    *hand = rules
+                        .hand(name)
+                        .ok_or(EncodingConfigError("Opponent moves must map to a hand in the rules."))?;
+                }
+                hands
+            }
+            None => [Hand::new(0), Hand::new(1), Hand::new(2)],
+        };
+        if opponent.iter().any(|hand| hand.index() >= rules.len()) {
+            return Err(EncodingConfigError("The rules have fewer than three hands."));
+        }
+
+        let names = columns(&file.player, ["X", "Y", "Z"])?;
+        let mut player = [PlayerMapping::Hand(Hand::new(0)); 3];
+        for (mapping, name) in player.iter_mut().zip(names) {
+            *mapping = PlayerMapping::named(name, rules).ok_or(EncodingConfigError(
+                "Player moves must map to a hand in the rules or to Loss, Draw or Win.",
+            ))?;
+        }
+
+        Ok(Self { opponent, player })
+    }
+
+    pub fn load(path: &str, rules: &Rules) -> Result<Self, Box<dyn std::error::Error>> {
+        let input = std::fs::read_to_string(path)?;
+        Ok(Self::from_toml(&input, rules)?)
+    }
+
+    pub fn opponent(&self) -> [Hand; 3] {
+        self.opponent
+    }
+
+    pub fn player(&self) -> [PlayerMapping; 3] {
+        self.player
+    }
+
+    // Writes the encoding back out in the format `from_toml` reads.
+    pub fn to_toml(&self, rules: &Rules) -> String {
+        let mut toml = String::from("[opponent]\n");
+        for (letter, hand) in ["A", "B", "C"].iter().zip(self.opponent) {
+            // Writing to a String can't fail.
+            writeln!(toml, "{} = \"{}\"", letter, rules.name(hand)).unwrap();
+        }
+        toml += "\n[player]\n";
+        for (letter, mapping) in ["X", "Y", "Z"].iter().zip(self.player) {
+            let name = match mapping {
+                PlayerMapping::Hand(hand) => rules.name(hand),
+                PlayerMapping::Outcome(outcome) => outcome.name(),
+            };
+            writeln!(toml, "{} = \"{}\"", letter, name).unwrap();
+        }
+        toml
+    }
+}
+
+impl InstructionParsingStrategy for ConfiguredEncodingStrategy {
+    fn parse_encoded(&self, inst: &EncodedInstruction, player: usize, rules: &Rules) -> Result<Round, InstructionParseError> {
+        decode(inst, player, rules, |mv| self.opponent[mv.index()], |mv| self.player[mv.index()])
+    }
+}
+
+// The values for each of `letters` in a table, which must have no other keys.
+fn columns<'a>(table: &'a BTreeMap<String, String>, letters: [&str; 3]) -> Result<[&'a str; 3], EncodingConfigError> {
+    if table.len() != letters.len() {
+        return Err(EncodingConfigError("Each table needs exactly one entry per letter (A, B, C or X, Y, Z)."));
+    }
+    let mut values = [""; 3];
+    for (value, letter) in values.iter_mut().zip(letters) {
+        *value = table
+            .get(letter)
+            .ok_or(EncodingConfigError("Each table needs exactly one entry per letter (A, B, C or X, Y, Z)."))?;
+    }
+    Ok(values)
+}
+
+custom_error!(EncodingConfigError);
@@ -0,0 +1,30 @@
+// A hand is a position in a game's `Rules`, which say what it's called, what it scores and what it beats.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct Hand(usize);
+
+impl Hand {
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Outcome {
+    Loss,
+    Win,
+    Draw,
+}
+
+impl Outcome {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Loss => "Loss",
+            Outcome::Draw => "Draw",
+            Outcome::Win => "Win",
+        }
+    }
+}
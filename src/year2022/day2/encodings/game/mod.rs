@@ -1,4 +1,6 @@
 mod hand;
 mod round;
+mod rules;
 pub use hand::{Hand, Outcome};
 pub use round::Round;
+pub use rules::{OutcomeScores, Rules, RulesError};
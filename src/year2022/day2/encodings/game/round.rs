@@ -0,0 +1,18 @@
+use super::{Hand, Rules};
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Round {
+    pub player: Hand,
+    pub opponent: Hand,
+}
+
+impl Round {
+    pub fn new(player: Hand, opponent: Hand) -> Self {
+        Self { player, opponent }
+    }
+
+    pub fn score(&self, rules: &Rules) -> u32 {
+        let outcome = rules.versus(self.player, self.opponent);
+        rules.hand_score(self.player) + rules.outcome_score(outcome)
+    }
+}
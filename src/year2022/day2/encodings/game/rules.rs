@@ -0,0 +1,195 @@
+use super::{Hand, Outcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutcomeScores {
+    pub loss: u32,
+    pub draw: u32,
+    pub win: u32,
+}
+
+impl Default for OutcomeScores {
+    fn default() -> Self {
+        Self {
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+// A rule table for rock-paper-scissors style games: the hands, what each scores when played,
+// which hand beats which, and what each outcome scores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    hand_scores: Vec<u32>,
+    // beats[a][b] is true when hand a beats hand b.
+    beats: Vec<Vec<bool>>,
+    outcome_scores: OutcomeScores,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+impl Rules {
+    // Rock, Paper and Scissors scoring 1, 2 and 3, with the puzzle's 0/3/6 for a loss, draw or win.
+    pub fn classic() -> Self {
+        Self::cyclic(&[("Rock", 1), ("Paper", 2), ("Scissors", 3)], OutcomeScores::default())
+            .expect("Classic rules are valid")
+    }
+
+    // Rock-Paper-Scissors-Lizard-Spock, hands scoring 1 to 5 in the order listed.
+    pub fn rpsls() -> Self {
+        Self::cyclic_scored_in_order(&["Rock", "Spock", "Paper", "Lizard", "Scissors"])
+    }
+
+    // RPS-15, hands scoring 1 to 15 in the order listed.
+    pub fn rps15() -> Self {
+        Self::cyclic_scored_in_order(&[
+            "Gun", "Lightning", "Devil", "Dragon", "Water", "Air", "Paper", "Sponge", "Wolf", "Tree", "Human",
+            "Snake", "Scissors", "Fire", "Rock",
+        ])
+    }
+
+    // The usual way to extend the game to any odd number of hands: listed in a circle, each hand
+    // beats the half of the others that come just before it (Paper beats Rock, Scissors beats Paper,
+    // and Rock wraps around to beat Scissors).
+    pub fn cyclic(hands: &[(&str, u32)], outcome_scores: OutcomeScores) -> Result<Self, RulesError> {
+        let n = hands.len();
+        if n.is_multiple_of(2) {
+            return Err(RulesError("A balanced game needs an odd number of hands."));
+        }
+        let beats = (0..n)
+            .map(|a| (0..n).map(|b| a != b && (a + n - b) % n <= n / 2).collect())
+            .collect();
+        Self::new(hands, beats, outcome_scores)
+    }
+
+    // Builds rules from explicit `(winner, loser)` pairs, which must settle every pairing of two
+    // different hands exactly once.
+    pub fn from_table(
+        hands: &[(&str, u32)],
+        wins: &[(&str, &str)],
+        outcome_scores: OutcomeScores,
+    ) -> Result<Self, RulesError> {
+        let n = hands.len();
+        let index_of = |name: &str| {
+            hands
+                .iter()
+                .position(|(h, _)| *h == name)
+                .ok_or(RulesError("Rule table refers to a hand that isn't listed."))
+        };
+        let mut beats = vec![vec![false; n]; n];
+        for (winner, loser) in wins {
+            let (w, l) = (index_of(winner)?, index_of(loser)?);
+            beats[w][l] = true;
+        }
+        Self::new(hands, beats, outcome_scores)
+    }
+
+    fn new(hands: &[(&str, u32)], beats: Vec<Vec<bool>>, outcome_scores: OutcomeScores) -> Result<Self, RulesError> {
+        let n = hands.len();
+        if n == 0 {
+            return Err(RulesError("A game needs at least one hand."));
+        }
+        if (0..n).any(|a| beats[a][a]) {
+            return Err(RulesError("A hand can't beat itself."));
+        }
+        if (0..n).any(|a| ((a + 1)..n).any(|b| beats[a][b] == beats[b][a])) {
+            return Err(RulesError("Every pair of hands needs exactly one winner."));
+        }
+        let mut names = hands.iter().map(|(name, _)| name.to_string()).collect::<Vec<String>>();
+        names.sort_unstable();
+        names.dedup();
+        if names.len() != n {
+            return Err(RulesError("Hand names must be unique."));
+        }
+        Ok(Self {
+            names: hands.iter().map(|(name, _)| name.to_string()).collect(),
+            hand_scores: hands.iter().map(|(_, score)| *score).collect(),
+            beats,
+            outcome_scores,
+        })
+    }
+
+    fn cyclic_scored_in_order(names: &[&str]) -> Self {
+        let hands = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (*name, i as u32 + 1))
+            .collect::<Vec<(&str, u32)>>();
+        Self::cyclic(&hands, OutcomeScores::default()).expect("Preset rules are valid")
+    }
+
+    pub fn with_outcome_scores(mut self, outcome_scores: OutcomeScores) -> Self {
+        self.outcome_scores = outcome_scores;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn hands(&self) -> impl Iterator<Item = Hand> {
+        (0..self.len()).map(Hand::new)
+    }
+
+    // Hand names are matched ignoring case.
+    pub fn hand(&self, name: &str) -> Option<Hand> {
+        self.names
+            .iter()
+            .position(|n| n.eq_ignore_ascii_case(name))
+            .map(Hand::new)
+    }
+
+    pub fn name(&self, hand: Hand) -> &str {
+        &self.names[hand.index()]
+    }
+
+    pub fn hand_score(&self, hand: Hand) -> u32 {
+        self.hand_scores[hand.index()]
+    }
+
+    pub fn outcome_scores(&self) -> OutcomeScores {
+        self.outcome_scores
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Loss => self.outcome_scores.loss,
+            Outcome::Draw => self.outcome_scores.draw,
+            Outcome::Win => self.outcome_scores.win,
+        }
+    }
+
+    pub fn beats(&self, hand: Hand, other: Hand) -> bool {
+        self.beats[hand.index()][other.index()]
+    }
+
+    pub fn versus(&self, hand: Hand, other: Hand) -> Outcome {
+        if self.beats(hand, other) {
+            Outcome::Win
+        } else if self.beats(other, hand) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    // The hand to play against `opponent` for the given outcome. When several hands would do,
+    // the one scoring most is picked, then the first listed. None if no hand gives that outcome.
+    pub fn hand_to_achieve(&self, opponent: Hand, outcome: Outcome) -> Option<Hand> {
+        self.hands()
+            .filter(|hand| self.versus(*hand, opponent) == outcome)
+            .min_by_key(|hand| (std::cmp::Reverse(self.hand_score(*hand)), *hand))
+    }
+}
+
+custom_error!(RulesError);
@@ -0,0 +1,232 @@
+use std::str::FromStr;
+
+// Repeats are expanded into rows, so they're capped to keep a short guide from asking for more memory
+// than there is.
+pub const MAX_REPEAT: usize = 10_000;
+pub const MAX_REPEATED_ROUNDS: usize = 1_000_000;
+
+// One row of a strategy guide: the opponent's move, then a move for each player following the
+// guide. The puzzle's guides have a single player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedInstruction {
+    opponent: OpponentMove,
+    players: Vec<PlayerMove>,
+}
+
+impl EncodedInstruction {
+    pub fn opponent(&self) -> &OpponentMove {
+        &self.opponent
+    }
+
+    // The first player's move.
+    pub fn player(&self) -> &PlayerMove {
+        &self.players[0]
+    }
+
+    pub fn players(&self) -> &[PlayerMove] {
+        &self.players
+    }
+
+    // The row as seen by one of its players, as a single-player row of its own.
+    pub fn for_player(&self, player: usize) -> Self {
+        Self {
+            opponent: self.opponent.clone(),
+            players: vec![self.players[player].clone()],
+        }
+    }
+}
+
+// A move is either one of the puzzle's letters, which an encoding gives a meaning to, or written
+// out by name, which means the same under every encoding. Names are checked against the guide's rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpponentMove {
+    Encoded(EncodedOpponentMove),
+    // A hand.
+    Named(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerMove {
+    Encoded(EncodedPlayerMove),
+    // A hand, or Loss, Draw or Win.
+    Named(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodedOpponentMove {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodedPlayerMove {
+    X,
+    Y,
+    Z,
+}
+
+impl EncodedOpponentMove {
+    pub fn index(&self) -> usize {
+        match self {
+            EncodedOpponentMove::A => 0,
+            EncodedOpponentMove::B => 1,
+            EncodedOpponentMove::C => 2,
+        }
+    }
+}
+
+impl EncodedPlayerMove {
+    pub fn index(&self) -> usize {
+        match self {
+            EncodedPlayerMove::X => 0,
+            EncodedPlayerMove::Y => 1,
+            EncodedPlayerMove::Z => 2,
+        }
+    }
+}
+
+// Parses a whole guide. Besides the puzzle's `A Y` rows it accepts:
+//
+//     # Comments, on their own line or after a row, and blank lines
+//     Rock Paper          # moves written out by name
+//     B Win
+//     A Y x10             # the same row ten times, up to `MAX_REPEAT`
+//     A Y X Z             # one move per player, the same number of players on every row
+pub fn parse_guide(input: &str) -> Result<Vec<EncodedInstruction>, InstructionParseError> {
+    let mut instructions: Vec<EncodedInstruction> = Vec::new();
+    for line in input.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (row, repeat) = match line.rsplit_once(char::is_whitespace) {
+            Some((row, count)) if is_repeat(count) => {
+                let repeat = count[1..]
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|r| *r <= MAX_REPEAT)
+                    .ok_or_else(|| InstructionParseError("A repeat count can be at most x10000.", count.to_string()))?;
+                (row, repeat)
+            }
+            _ => (line, 1),
+        };
+        if repeat == 0 {
+            return Err(InstructionParseError("A repeat count must be at least x1.", line.to_string()));
+        }
+        if repeat > 1 && instructions.len() + repeat > MAX_REPEATED_ROUNDS {
+            return Err(InstructionParseError(
+                "Repeats can only take a guide to 1000000 rounds.",
+                line.to_string(),
+            ));
+        }
+        let instruction = row.parse::<EncodedInstruction>()?;
+        if instructions
+            .first()
+            .is_some_and(|first| first.players.len() != instruction.players.len())
+        {
+            return Err(InstructionParseError(
+                "Every row of a guide needs the same number of players.",
+                line.to_string(),
+            ));
+        }
+        instructions.extend(std::iter::repeat_n(instruction, repeat));
+    }
+    Ok(instructions)
+}
+
+impl FromStr for EncodedInstruction {
+    type Err = InstructionParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let opponent = tokens
+            .next()
+            .ok_or_else(|| InstructionParseError("Invalid instruction string.", s.to_string()))?
+            .parse::<OpponentMove>()?;
+        let players = tokens.map(|t| t.parse::<PlayerMove>()).collect::<Result<Vec<PlayerMove>, _>>()?;
+        if players.is_empty() {
+            return Err(InstructionParseError(
+                "Invalid instruction string. Expected a player move after the opponent's.",
+                s.to_string(),
+            ));
+        }
+        Ok(Self { opponent, players })
+    }
+}
+
+impl FromStr for OpponentMove {
+    type Err = InstructionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<EncodedOpponentMove>() {
+            Ok(mv) => Ok(OpponentMove::Encoded(mv)),
+            Err(_) if is_name(s) => Ok(OpponentMove::Named(s.to_string())),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl FromStr for PlayerMove {
+    type Err = InstructionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<EncodedPlayerMove>() {
+            Ok(mv) => Ok(PlayerMove::Encoded(mv)),
+            Err(_) if is_name(s) => Ok(PlayerMove::Named(s.to_string())),
+            Err(_) if is_repeat(s) => Err(InstructionParseError(
+                "A repeat count like x10 must come last on its row.",
+                s.to_string(),
+            )),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl FromStr for EncodedOpponentMove {
+    type Err = InstructionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(EncodedOpponentMove::A),
+            "B" => Ok(EncodedOpponentMove::B),
+            "C" => Ok(EncodedOpponentMove::C),
+            _ => Err(InstructionParseError(
+                "Invalid opponent move. Expected 'A', 'B', 'C' or a hand's name.",
+                s.to_string(),
+            )),
+        }
+    }
+}
+
+impl FromStr for EncodedPlayerMove {
+    type Err = InstructionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(EncodedPlayerMove::X),
+            "Y" => Ok(EncodedPlayerMove::Y),
+            "Z" => Ok(EncodedPlayerMove::Z),
+            _ => Err(InstructionParseError(
+                "Invalid player move. Expected 'X', 'Y', 'Z', a hand's name, or Loss, Draw or Win.",
+                s.to_string(),
+            )),
+        }
+    }
+}
+
+// Single letters are kept for encoded moves, so a stray `D` is an error rather than a hand called D.
+fn is_name(s: &str) -> bool {
+    s.len() > 1 && s.chars().all(|c| c.is_alphabetic() || c == '-' || c == '_')
+}
+
+fn is_repeat(s: &str) -> bool {
+    s.len() > 1 && s.starts_with('x') && s[1..].chars().all(|c| c.is_ascii_digit())
+}
+
+token_error!(InstructionParseError);
+
+impl InstructionParseError {
+    pub(super) fn new(message: &'static str, token: String) -> Self {
+        Self(message, token)
+    }
+}
@@ -0,0 +1,15 @@
+use super::*;
+
+pub struct LossDrawWinEncodingStrategy {}
+
+impl InstructionParsingStrategy for LossDrawWinEncodingStrategy {
+    fn parse_encoded(&self, inst: &EncodedInstruction, player: usize, rules: &Rules) -> Result<Round, InstructionParseError> {
+        decode(inst, player, rules, opponent_hand, |mv| {
+            PlayerMapping::Outcome(match mv {
+                EncodedPlayerMove::X => Outcome::Loss,
+                EncodedPlayerMove::Y => Outcome::Draw,
+                EncodedPlayerMove::Z => Outcome::Win,
+            })
+        })
+    }
+}
@@ -0,0 +1,86 @@
+mod configured;
+mod game;
+mod instructions;
+mod loss_draw_win;
+mod natural;
+
+pub use configured::{ConfiguredEncodingStrategy, EncodingConfigError};
+pub use game::{Hand, Outcome, OutcomeScores, Round, Rules, RulesError};
+pub use instructions::{
+    parse_guide, EncodedInstruction, EncodedOpponentMove, EncodedPlayerMove, InstructionParseError, OpponentMove,
+    PlayerMove, MAX_REPEAT, MAX_REPEATED_ROUNDS,
+};
+pub use loss_draw_win::LossDrawWinEncodingStrategy;
+pub use natural::NaturalEncodingStrategy;
+
+pub trait InstructionParsingStrategy {
+    // The round one of the row's players plays, numbered from 0. Fails if the row has no move for that
+    // player or names a move that isn't in the rules.
+    fn parse_encoded(&self, inst: &EncodedInstruction, player: usize, rules: &Rules) -> Result<Round, InstructionParseError>;
+}
+
+// What a player's move stands for: a hand to play, or an outcome to aim for.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum PlayerMapping {
+    Hand(Hand),
+    Outcome(Outcome),
+}
+
+impl PlayerMapping {
+    // Names are matched ignoring case, and a name is read as a hand before an outcome.
+    pub fn named(name: &str, rules: &Rules) -> Option<Self> {
+        let outcome = [Outcome::Loss, Outcome::Draw, Outcome::Win]
+            .into_iter()
+            .find(|o| o.name().eq_ignore_ascii_case(name));
+        match (rules.hand(name), outcome) {
+            (Some(hand), _) => Some(PlayerMapping::Hand(hand)),
+            (None, Some(outcome)) => Some(PlayerMapping::Outcome(outcome)),
+            (None, None) => None,
+        }
+    }
+
+    // Every valid rule table has a hand for each outcome against a hand that isn't unbeaten
+    // or unbeatable; fall back to a draw otherwise.
+    pub fn play_against(&self, opponent: Hand, rules: &Rules) -> Hand {
+        match self {
+            PlayerMapping::Hand(hand) => *hand,
+            PlayerMapping::Outcome(outcome) => rules.hand_to_achieve(opponent, *outcome).unwrap_or(opponent),
+        }
+    }
+}
+
+// A, B and C (and X, Y and Z) stand for the first three hands of the rules in order.
+fn opponent_hand(mv: EncodedOpponentMove) -> Hand {
+    Hand::new(mv.index())
+}
+
+// Reads an instruction for one of its players, with `opponent` and `player` giving the encoded letters
+// their meaning. Moves written out by name mean the same under every encoding.
+fn decode(
+    inst: &EncodedInstruction,
+    index: usize,
+    rules: &Rules,
+    opponent: impl Fn(EncodedOpponentMove) -> Hand,
+    player: impl Fn(EncodedPlayerMove) -> PlayerMapping,
+) -> Result<Round, InstructionParseError> {
+    let opponent = match inst.opponent() {
+        OpponentMove::Encoded(mv) => opponent(*mv),
+        OpponentMove::Named(name) => rules.hand(name).ok_or_else(|| {
+            InstructionParseError::new("The instruction names an opponent's hand that isn't in the rules.", name.clone())
+        })?,
+    };
+    let mv = inst
+        .players()
+        .get(index)
+        .ok_or_else(|| InstructionParseError::new("The instruction has no move for that player.", index.to_string()))?;
+    let player = match mv {
+        PlayerMove::Encoded(mv) => player(*mv),
+        PlayerMove::Named(name) => PlayerMapping::named(name, rules).ok_or_else(|| {
+            InstructionParseError::new(
+                "The instruction names a player's move that's neither a hand in the rules nor Loss, Draw or Win.",
+                name.clone(),
+            )
+        })?,
+    };
+    Ok(Round::new(player.play_against(opponent, rules), opponent))
+}
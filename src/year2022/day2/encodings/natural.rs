@@ -0,0 +1,9 @@
+use super::*;
+
+pub struct NaturalEncodingStrategy {}
+
+impl InstructionParsingStrategy for NaturalEncodingStrategy {
+    fn parse_encoded(&self, inst: &EncodedInstruction, player: usize, rules: &Rules) -> Result<Round, InstructionParseError> {
+        decode(inst, player, rules, opponent_hand, |mv| PlayerMapping::Hand(Hand::new(mv.index())))
+    }
+}
@@ -0,0 +1,235 @@
+use crate::util::*;
+
+mod breakdown;
+mod encoding_search;
+mod encodings;
+mod strategy_guide;
+mod tournament;
+
+pub use breakdown::{EncodingComparison, GuideBreakdown, RoundBreakdown};
+pub use encoding_search::{DecodedEncoding, EncodingSearchResult};
+pub use encodings::*;
+pub use strategy_guide::{RPSStrategyGuide, StrategyGuideError};
+pub use tournament::{
+    CopycatBot, FrequencyBot, GuideBot, MarkovBot, Player, RandomBot, Standing, Tournament, TournamentReport, Turn,
+};
+
+solution!(Year 2022 Day 2 => RPSStrategyGuide);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given_test() {
+        let input_str = "
+        A Y
+        B X
+        C Z
+        ";
+
+        let mut guide = RPSStrategyGuide::new_from_str(input_str).expect("Failed to parse guide.");
+
+        assert_eq!(guide.part_1(), "15");
+        assert_eq!(guide.part_2(), "12");
+    }
+
+    #[test]
+    fn rule_tables() {
+        let rules = Rules::rpsls();
+        let (rock, spock, lizard) = (rules.hand("rock").unwrap(), rules.hand("Spock").unwrap(), rules.hand("Lizard").unwrap());
+        assert_eq!(rules.versus(spock, rock), Outcome::Win);
+        assert_eq!(rules.versus(rock, lizard), Outcome::Win);
+        assert_eq!(rules.versus(lizard, spock), Outcome::Win);
+        assert_eq!(rules.versus(lizard, lizard), Outcome::Draw);
+        for hand in Rules::rps15().hands() {
+            assert_eq!(Rules::rps15().hands().filter(|other| Rules::rps15().beats(hand, *other)).count(), 7);
+        }
+
+        let table = Rules::from_table(
+            &[("Rock", 1), ("Paper", 2), ("Scissors", 3)],
+            &[("Paper", "Rock"), ("Scissors", "Paper"), ("Rock", "Scissors")],
+            OutcomeScores::default(),
+        );
+        assert_eq!(table.unwrap(), Rules::classic());
+        assert!(Rules::from_table(&[("Rock", 1), ("Paper", 2)], &[], OutcomeScores::default()).is_err());
+        assert!(Rules::cyclic(&[("A", 1), ("B", 1)], OutcomeScores::default()).is_err());
+
+        // The same guide under a different scoring.
+        let scores = OutcomeScores { loss: 0, draw: 1, win: 10 };
+        let guide = RPSStrategyGuide::new_from_str("A Y\nB X\nC Z")
+            .unwrap()
+            .with_rules(Rules::classic().with_outcome_scores(scores))
+            .unwrap();
+        assert_eq!(guide.get_total_score_using_encoding(&NaturalEncodingStrategy {}), 12 + 1 + 4);
+    }
+
+    #[test]
+    fn configured_encodings() {
+        let guide = RPSStrategyGuide::new_from_str("A Y\nB X\nC Z").unwrap();
+        let rules = guide.rules();
+        let natural = ConfiguredEncodingStrategy::from_toml("[player]\nX = \"Rock\"\nY = \"Paper\"\nZ = \"Scissors\"", rules).unwrap();
+        assert_eq!(guide.get_total_score_using_encoding(&natural), 15);
+        let outcomes = ConfiguredEncodingStrategy::from_toml(
+            "[opponent]\nA = \"rock\"\nB = \"paper\"\nC = \"scissors\"\n[player]\nX = \"loss\"\nY = \"Draw\"\nZ = \"WIN\"",
+            rules,
+        )
+        .unwrap();
+        assert_eq!(guide.get_total_score_using_encoding(&outcomes), 12);
+        assert_eq!(ConfiguredEncodingStrategy::from_toml(&outcomes.to_toml(rules), rules).unwrap(), outcomes);
+
+        assert!(ConfiguredEncodingStrategy::from_toml("[player]\nX = \"Rock\"\nY = \"Paper\"", rules).is_err());
+        assert!(ConfiguredEncodingStrategy::from_toml("[player]\nX = \"Rock\"\nY = \"Paper\"\nZ = \"Spock\"", rules).is_err());
+        assert!(ConfiguredEncodingStrategy::from_toml("[player]\nX = \"Rock\"\nY = \"Paper\"\nW = \"Win\"", rules).is_err());
+
+        // Winning every round while scoring the most for each hand played.
+        let best = guide.best_player_encoding();
+        assert_eq!(best.score, 24);
+        assert_eq!(guide.get_total_score_using_encoding(&best.encoding), 24);
+        let (rock, paper, scissors) = (Hand::new(0), Hand::new(1), Hand::new(2));
+        assert_eq!(
+            best.encoding.player(),
+            [PlayerMapping::Hand(scissors), PlayerMapping::Hand(paper), PlayerMapping::Hand(rock)]
+        );
+        assert_eq!(guide.best_encoding().score, 24);
+    }
+
+    #[test]
+    fn decodes_encodings() {
+        let guide = RPSStrategyGuide::new_from_str("A Y\nB X\nC Z").unwrap();
+        let natural = [Hand::new(0), Hand::new(1), Hand::new(2)];
+        let (loss, draw, win) = (Outcome::Loss, Outcome::Draw, Outcome::Win);
+        let outcomes = [PlayerMapping::Outcome(loss), PlayerMapping::Outcome(draw), PlayerMapping::Outcome(win)];
+        assert_eq!(
+            guide.decode_player_encoding(12),
+            DecodedEncoding::Unique(ConfiguredEncodingStrategy::new(natural, outcomes))
+        );
+        assert_eq!(guide.decode_player_encoding(25), DecodedEncoding::NoMatch);
+
+        // Part 1's answer is shared by four hand readings and four outcome readings.
+        match guide.decode_player_encoding(15) {
+            DecodedEncoding::Ambiguous(matches) => {
+                assert_eq!(matches.len(), 8);
+                assert_eq!(matches[0], ConfiguredEncodingStrategy::new(natural, natural.map(PlayerMapping::Hand)));
+                assert!(matches.iter().all(|m| guide.get_total_score_using_encoding(m) == 15));
+                assert_eq!(matches.iter().filter(|m| matches!(m.player()[0], PlayerMapping::Outcome(_))).count(), 4);
+            }
+            other => panic!("Expected an ambiguous decoding, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn tournaments() {
+        let guide = RPSStrategyGuide::new_from_str("A Y\nB X\nC Z").unwrap();
+        let players = || -> Vec<Box<dyn Player>> {
+            vec![
+                Box::new(RandomBot::new()),
+                Box::new(FrequencyBot::new()),
+                Box::new(MarkovBot::new()),
+                Box::new(CopycatBot),
+                Box::new(GuideBot::new(&guide, &NaturalEncodingStrategy {})),
+            ]
+        };
+        let tournament = Tournament::new(Rules::classic()).with_rounds(60).with_matches(40);
+        let report = tournament.run(&mut players());
+        for standing in &report.standings {
+            assert_eq!(standing.matches, 4 * 40);
+            assert_eq!(standing.wins + standing.draws + standing.losses, standing.matches);
+            assert_eq!(standing.rounds_won + standing.rounds_drawn + standing.rounds_lost, 4 * 40 * 60);
+        }
+        // A guide repeats itself, which the Markov bot picks up on within a few rounds.
+        assert_eq!(report.head_to_head[2][4], 40);
+        assert_eq!(report.ranked()[0].name, "Markov");
+
+        // The same seed replays the same tournament; another seed changes the random bot's luck.
+        assert_eq!(tournament.run(&mut players()), report);
+        let reseeded = Tournament::new(Rules::classic()).with_rounds(60).with_matches(40).with_seed(7);
+        assert_ne!(reseeded.run(&mut players()).standings[0], report.standings[0]);
+
+        // Bots play any rule table.
+        let report = Tournament::new(Rules::rpsls()).with_rounds(20).with_matches(5).run(&mut players()[..4]);
+        assert!(report.to_string().contains("Copycat"));
+    }
+
+    #[test]
+    fn breakdowns() {
+        let guide = RPSStrategyGuide::new_from_str("A Y\nB X\nC Z").unwrap();
+        let natural = guide.breakdown(&NaturalEncodingStrategy {});
+        assert_eq!(natural.rounds.iter().map(|r| r.running_total).collect::<Vec<u32>>(), vec![8, 9, 15]);
+        assert_eq!((natural.wins, natural.draws, natural.losses), (1, 1, 1));
+        assert_eq!((natural.hand_score, natural.outcome_score, natural.total), (6, 9, 15));
+        assert_eq!(natural.hand_counts, vec![1, 1, 1]);
+        assert_eq!(
+            natural.to_csv(guide.rules()),
+            "round,opponent,player,outcome,hand_score,outcome_score,score,running_total\n\
+             1,Rock,Paper,Win,2,6,8,8\n\
+             2,Paper,Rock,Loss,1,0,1,9\n\
+             3,Scissors,Scissors,Draw,3,3,6,15\n"
+        );
+
+        let comparison = guide.compare_puzzle_encodings();
+        assert_eq!(comparison.breakdowns[1].total, 12);
+        assert_eq!(comparison.breakdowns[1].hand_counts, vec![3, 0, 0]);
+        let csv = comparison.to_csv(guide.rules());
+        let lines = csv.lines().collect::<Vec<&str>>();
+        assert!(lines[0].starts_with("round,natural_opponent,natural_player,natural_outcome,"));
+        assert!(lines[0].contains(",natural_running_total,loss_draw_win_opponent,loss_draw_win_player,"));
+        assert_eq!(lines[1], "1,Rock,Paper,Win,2,6,8,8,Rock,Rock,Draw,1,3,4,4");
+        assert_eq!(lines.len(), 4);
+
+        // Each encoding's rows are against the opponent it reads.
+        let rules = guide.rules();
+        let shifted = ConfiguredEncodingStrategy::from_toml(
+            "[opponent]\nA = \"Paper\"\nB = \"Scissors\"\nC = \"Rock\"\n[player]\nX = \"Rock\"\nY = \"Paper\"\nZ = \"Scissors\"",
+            rules,
+        )
+        .unwrap();
+        let comparison = guide.compare_encodings([("natural", &NaturalEncodingStrategy {}), ("shifted", &shifted)]);
+        let csv = comparison.to_csv(rules);
+        assert_eq!(csv.lines().nth(1), Some("1,Rock,Paper,Win,2,6,8,8,Paper,Paper,Draw,2,3,5,5"));
+    }
+
+    #[test]
+    fn extended_format() {
+        let input_str = "
+        # The example, written three ways.
+        A Y
+        Paper Rock   # B X
+        C Z x1
+
+        A Y x3
+        ";
+        let guide = RPSStrategyGuide::new_from_str(input_str).expect("Failed to parse guide.");
+        assert_eq!(guide.get_total_score_using_encoding(&NaturalEncodingStrategy {}), 15 + 3 * 8);
+        // Named moves don't change with the encoding.
+        assert_eq!(guide.get_total_score_using_encoding(&LossDrawWinEncodingStrategy {}), 4 + 1 + 7 + 3 * 4);
+        assert_eq!(guide.rounds(&NaturalEncodingStrategy {}).len(), 6);
+
+        let guide = RPSStrategyGuide::new_from_str("A Win X\nB Y Scissors").unwrap();
+        assert_eq!(guide.player_count(), 2);
+        assert_eq!(guide.get_player_scores_using_encoding(&NaturalEncodingStrategy {}), vec![8 + 5, 4 + 9]);
+
+        let spock = RPSStrategyGuide::new_from_str_with_rules("Spock Lizard", Rules::rpsls()).unwrap();
+        assert_eq!(spock.get_total_score_using_encoding(&NaturalEncodingStrategy {}), 4 + 6);
+        assert!(RPSStrategyGuide::new_from_str("Spock Lizard").is_err());
+        assert!(spock.with_rules(Rules::classic()).is_err());
+
+        for bad in ["A", "D Y", "A Q", "A Y x0", "A x2 Y", "A Y 3", "A Y\nA Y Z", "A Y x99999999999", "A Y x10001"] {
+            assert!(RPSStrategyGuide::new_from_str(bad).is_err(), "{} should not parse", bad);
+        }
+        assert_eq!(RPSStrategyGuide::new_from_str("A Y x10000").unwrap().rounds(&NaturalEncodingStrategy {}).len(), 10000);
+        assert!(RPSStrategyGuide::new_from_str(&"A Y x10000\n".repeat(101)).is_err());
+        // Errors name what they couldn't make sense of.
+        let error = |input: &str| RPSStrategyGuide::new_from_str(input).err().unwrap().to_string();
+        assert!(error("A Q").ends_with("Got 'Q'."));
+        assert!(error("Rock Lizard").ends_with("Got 'Lizard'."));
+
+        // Encodings read rows on their own too, against whatever rules they're given.
+        let rows = parse_guide("Spock Lizard Y").unwrap();
+        let natural = NaturalEncodingStrategy {};
+        assert!(natural.parse_encoded(&rows[0], 1, &Rules::rpsls()).is_ok());
+        let unknown = natural.parse_encoded(&rows[0], 0, &Rules::classic()).unwrap_err();
+        assert!(unknown.to_string().ends_with("Got 'Spock'."));
+        assert!(natural.parse_encoded(&rows[0], 2, &Rules::rpsls()).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{read_input_to_str, AOCSolution};
+
+use super::encodings::*;
+
+pub struct RPSStrategyGuide {
+    instructions: Vec<EncodedInstruction>,
+    rules: Rules,
+}
+
+impl AOCSolution for RPSStrategyGuide {
+    fn load_from(input_file_path: &str) -> Result<Box<Self>, Box<dyn std::error::Error>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        let encoding = NaturalEncodingStrategy {};
+        let score = self.get_total_score_using_encoding(&encoding);
+        format!("{}", score)
+    }
+
+    fn part_2(&mut self) -> String {
+        let encoding = LossDrawWinEncodingStrategy {};
+        let score = self.get_total_score_using_encoding(&encoding);
+        format!("{}", score)
+    }
+
+    fn report(&mut self) -> Option<String> {
+        self.breakdown_report()
+    }
+}
+
+impl RPSStrategyGuide {
+    fn new_from_file(input_file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let input_str = read_input_to_str(input_file_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    // Reads the puzzle's format or the extended one described at `parse_guide`.
+    pub fn new_from_str(input_str: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::new_from_str_with_rules(input_str, Rules::classic())
+    }
+
+    // As `new_from_str`, for guides that name hands from other rules.
+    pub fn new_from_str_with_rules(input_str: &str, rules: Rules) -> Result<Self, Box<dyn std::error::Error>> {
+        let guide = Self {
+            instructions: parse_guide(input_str)?,
+            rules: Rules::classic(),
+        };
+        Ok(guide.with_rules(rules)?)
+    }
+
+    // Plays the guide under different rules. The encoded moves still only reach the first three hands,
+    // and any moves written out by name must be in the new rules.
+    pub fn with_rules(mut self, rules: Rules) -> Result<Self, StrategyGuideError> {
+        if rules.len() < 3 {
+            return Err(StrategyGuideError(
+                "The strategy guide needs rules with at least three hands.",
+                rules.len().to_string(),
+            ));
+        }
+        for inst in &self.instructions {
+            if let OpponentMove::Named(name) = inst.opponent() {
+                if rules.hand(name).is_none() {
+                    return Err(StrategyGuideError(
+                        "The guide names an opponent's hand that isn't in its rules.",
+                        name.clone(),
+                    ));
+                }
+            }
+            for mv in inst.players() {
+                if let PlayerMove::Named(name) = mv {
+                    if PlayerMapping::named(name, &rules).is_none() {
+                        return Err(StrategyGuideError(
+                            "The guide names a player's move that's neither a hand in its rules nor Loss, Draw or Win.",
+                            name.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+        self.rules = rules;
+        Ok(self)
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub(super) fn instructions(&self) -> &[EncodedInstruction] {
+        &self.instructions
+    }
+
+    // How many players each row has a move for. Rounds and scores are the first player's unless
+    // another is asked for.
+    pub fn player_count(&self) -> usize {
+        self.instructions.first().map_or(1, |inst| inst.players().len())
+    }
+
+    pub fn rounds(&self, encoding: &dyn InstructionParsingStrategy) -> Vec<Round> {
+        self.rounds_for_player(encoding, 0)
+    }
+
+    // Players are numbered from 0 up to `player_count`. Every row has a move for each of them, and
+    // `with_rules` has checked the rows' named moves.
+    pub fn rounds_for_player(&self, encoding: &dyn InstructionParsingStrategy, player: usize) -> Vec<Round> {
+        self.instructions
+            .iter()
+            .map(|inst| {
+                encoding
+                    .parse_encoded(inst, player, &self.rules)
+                    .expect("Guides check every row against their rules")
+            })
+            .collect()
+    }
+
+    pub fn get_total_score_using_encoding(&self, encoding: &dyn InstructionParsingStrategy) -> u32 {
+        self.rounds(encoding).iter().map(|round| round.score(&self.rules)).sum()
+    }
+
+    // Every player's total, in the order their moves appear on each row.
+    pub fn get_player_scores_using_encoding(&self, encoding: &dyn InstructionParsingStrategy) -> Vec<u32> {
+        (0..self.player_count())
+            .map(|player| {
+                self.rounds_for_player(encoding, player)
+                    .iter()
+                    .map(|round| round.score(&self.rules))
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+token_error!(StrategyGuideError);
@@ -0,0 +1,164 @@
+use super::{Player, Rng, Turn};
+use crate::year2022::day2::encodings::{Hand, InstructionParsingStrategy, Outcome, Rules};
+use crate::year2022::day2::RPSStrategyGuide;
+
+// Plays any hand with equal chance.
+pub struct RandomBot {
+    rng: Rng,
+}
+
+impl RandomBot {
+    pub fn new() -> Self {
+        Self { rng: Rng::new(0) }
+    }
+}
+
+impl Default for RandomBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Player for RandomBot {
+    fn name(&self) -> &str {
+        "Random"
+    }
+
+    fn reset(&mut self, seed: u64) {
+        self.rng = Rng::new(seed);
+    }
+
+    fn play(&mut self, _history: &[Turn], rules: &Rules) -> Hand {
+        Hand::new(self.rng.below(rules.len()))
+    }
+}
+
+// Beats whatever the opponent has played most so far, playing at random until there's a history.
+pub struct FrequencyBot {
+    rng: Rng,
+}
+
+impl FrequencyBot {
+    pub fn new() -> Self {
+        Self { rng: Rng::new(0) }
+    }
+}
+
+impl Default for FrequencyBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Player for FrequencyBot {
+    fn name(&self) -> &str {
+        "Frequency"
+    }
+
+    fn reset(&mut self, seed: u64) {
+        self.rng = Rng::new(seed);
+    }
+
+    fn play(&mut self, history: &[Turn], rules: &Rules) -> Hand {
+        let mut counts = vec![0; rules.len()];
+        for turn in history {
+            counts[turn.opponent.index()] += 1;
+        }
+        // The earliest listed hand wins a tie.
+        let favourite = (0..rules.len())
+            .max_by_key(|h| (counts[*h], std::cmp::Reverse(*h)))
+            .filter(|h| counts[*h] > 0);
+        counter_or_random(favourite.map(Hand::new), rules, &mut self.rng)
+    }
+}
+
+// Learns what the opponent tends to play after each of their own hands, and beats the likeliest
+// follow-up to their last one. Plays at random when it has nothing to go on.
+pub struct MarkovBot {
+    rng: Rng,
+}
+
+impl MarkovBot {
+    pub fn new() -> Self {
+        Self { rng: Rng::new(0) }
+    }
+}
+
+impl Default for MarkovBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Player for MarkovBot {
+    fn name(&self) -> &str {
+        "Markov"
+    }
+
+    fn reset(&mut self, seed: u64) {
+        self.rng = Rng::new(seed);
+    }
+
+    fn play(&mut self, history: &[Turn], rules: &Rules) -> Hand {
+        let n = rules.len();
+        let mut transitions = vec![vec![0; n]; n];
+        for pair in history.windows(2) {
+            transitions[pair[0].opponent.index()][pair[1].opponent.index()] += 1;
+        }
+        let predicted = history.last().and_then(|last| {
+            let row = &transitions[last.opponent.index()];
+            (0..n).max_by_key(|h| (row[*h], std::cmp::Reverse(*h))).filter(|h| row[*h] > 0)
+        });
+        counter_or_random(predicted.map(Hand::new), rules, &mut self.rng)
+    }
+}
+
+// Plays whatever the opponent played last, opening with the first hand.
+pub struct CopycatBot;
+
+impl Player for CopycatBot {
+    fn name(&self) -> &str {
+        "Copycat"
+    }
+
+    fn reset(&mut self, _seed: u64) {}
+
+    fn play(&mut self, history: &[Turn], _rules: &Rules) -> Hand {
+        history.last().map_or(Hand::new(0), |last| last.opponent)
+    }
+}
+
+// Follows a strategy guide's moves in order, read with the given encoding, starting over once it
+// runs out. The hands come from the guide's rules, so they should match the tournament's.
+pub struct GuideBot {
+    hands: Vec<Hand>,
+}
+
+impl GuideBot {
+    pub fn new(guide: &RPSStrategyGuide, encoding: &dyn InstructionParsingStrategy) -> Self {
+        Self {
+            hands: guide.rounds(encoding).iter().map(|round| round.player).collect(),
+        }
+    }
+}
+
+impl Player for GuideBot {
+    fn name(&self) -> &str {
+        "Guide"
+    }
+
+    fn reset(&mut self, _seed: u64) {}
+
+    fn play(&mut self, history: &[Turn], _rules: &Rules) -> Hand {
+        if self.hands.is_empty() {
+            return Hand::new(0);
+        }
+        self.hands[history.len() % self.hands.len()]
+    }
+}
+
+fn counter_or_random(predicted: Option<Hand>, rules: &Rules, rng: &mut Rng) -> Hand {
+    predicted
+        .and_then(|hand| rules.hand_to_achieve(hand, Outcome::Win))
+        .unwrap_or_else(|| Hand::new(rng.below(rules.len())))
+}
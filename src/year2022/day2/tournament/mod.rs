@@ -0,0 +1,287 @@
+use super::encodings::{Hand, Outcome, Round, Rules};
+use std::fmt;
+
+mod bots;
+
+pub use bots::{CopycatBot, FrequencyBot, GuideBot, MarkovBot, RandomBot};
+
+// One round from a player's point of view.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Turn {
+    pub own: Hand,
+    pub opponent: Hand,
+}
+
+pub trait Player {
+    fn name(&self) -> &str;
+    // Called before every match, so nothing carries over from the last opponent. Players that
+    // make random choices should draw them from `seed` so tournaments can be replayed.
+    fn reset(&mut self, seed: u64);
+    // Picks the next hand, given every earlier round of this match.
+    fn play(&mut self, history: &[Turn], rules: &Rules) -> Hand;
+}
+
+// A round-robin tournament: every pair of players meets for `matches` matches of `rounds` rounds,
+// scored round by round with `Round::score`. A match goes to whoever scores more.
+pub struct Tournament {
+    rules: Rules,
+    rounds: usize,
+    matches: usize,
+    seed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub matches: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub rounds_won: usize,
+    pub rounds_drawn: usize,
+    pub rounds_lost: usize,
+    pub total_score: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TournamentReport {
+    // In the order the players were entered.
+    pub standings: Vec<Standing>,
+    // head_to_head[a][b] is how many matches player a won against player b.
+    pub head_to_head: Vec<Vec<usize>>,
+    pub matches_per_pairing: usize,
+    pub rounds_per_match: usize,
+}
+
+impl Tournament {
+    pub fn new(rules: Rules) -> Self {
+        Self {
+            rules,
+            rounds: 100,
+            matches: 50,
+            seed: 2022,
+        }
+    }
+
+    pub fn with_rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    pub fn with_matches(mut self, matches: usize) -> Self {
+        self.matches = matches;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run(&self, players: &mut [Box<dyn Player>]) -> TournamentReport {
+        let n = players.len();
+        let mut standings = players
+            .iter()
+            .map(|p| Standing {
+                name: p.name().to_string(),
+                matches: 0,
+                wins: 0,
+                draws: 0,
+                losses: 0,
+                rounds_won: 0,
+                rounds_drawn: 0,
+                rounds_lost: 0,
+                total_score: 0,
+            })
+            .collect::<Vec<Standing>>();
+        let mut head_to_head = vec![vec![0; n]; n];
+
+        for a in 0..n {
+            for b in (a + 1)..n {
+                for m in 0..self.matches {
+                    let mut rng = Rng::new(self.seed ^ (((a * n + b) * self.matches + m) as u64).wrapping_mul(GOLDEN_GAMMA));
+                    // a < b, so splitting at b borrows both players at once.
+                    let (left, right) = players.split_at_mut(b);
+                    let (first, second) = (&mut left[a], &mut right[0]);
+                    first.reset(rng.next_u64());
+                    second.reset(rng.next_u64());
+                    let (score_a, score_b) = self.play_match(first.as_mut(), second.as_mut(), &mut standings, (a, b));
+                    standings[a].matches += 1;
+                    standings[b].matches += 1;
+                    if score_a > score_b {
+                        standings[a].wins += 1;
+                        standings[b].losses += 1;
+                        head_to_head[a][b] += 1;
+                    } else if score_b > score_a {
+                        standings[b].wins += 1;
+                        standings[a].losses += 1;
+                        head_to_head[b][a] += 1;
+                    } else {
+                        standings[a].draws += 1;
+                        standings[b].draws += 1;
+                    }
+                }
+            }
+        }
+
+        TournamentReport {
+            standings,
+            head_to_head,
+            matches_per_pairing: self.matches,
+            rounds_per_match: self.rounds,
+        }
+    }
+
+    fn play_match(
+        &self,
+        first: &mut dyn Player,
+        second: &mut dyn Player,
+        standings: &mut [Standing],
+        (a, b): (usize, usize),
+    ) -> (u64, u64) {
+        let mut history_a = Vec::with_capacity(self.rounds);
+        let mut history_b = Vec::with_capacity(self.rounds);
+        let (mut score_a, mut score_b) = (0, 0);
+        for _ in 0..self.rounds {
+            let hand_a = first.play(&history_a, &self.rules);
+            let hand_b = second.play(&history_b, &self.rules);
+            score_a += Round::new(hand_a, hand_b).score(&self.rules) as u64;
+            score_b += Round::new(hand_b, hand_a).score(&self.rules) as u64;
+            match self.rules.versus(hand_a, hand_b) {
+                Outcome::Win => {
+                    standings[a].rounds_won += 1;
+                    standings[b].rounds_lost += 1;
+                }
+                Outcome::Loss => {
+                    standings[a].rounds_lost += 1;
+                    standings[b].rounds_won += 1;
+                }
+                Outcome::Draw => {
+                    standings[a].rounds_drawn += 1;
+                    standings[b].rounds_drawn += 1;
+                }
+            }
+            history_a.push(Turn { own: hand_a, opponent: hand_b });
+            history_b.push(Turn { own: hand_b, opponent: hand_a });
+        }
+        standings[a].total_score += score_a;
+        standings[b].total_score += score_b;
+        (score_a, score_b)
+    }
+}
+
+impl Standing {
+    pub fn win_rate(&self) -> f64 {
+        rate(self.wins, self.matches)
+    }
+
+    pub fn round_win_rate(&self) -> f64 {
+        rate(self.rounds_won, self.rounds_won + self.rounds_drawn + self.rounds_lost)
+    }
+
+    // A 95% Wilson score interval for the match win rate.
+    pub fn win_rate_interval(&self) -> (f64, f64) {
+        if self.matches == 0 {
+            return (0.0, 1.0);
+        }
+        let z = 1.96;
+        let n = self.matches as f64;
+        let p = self.win_rate();
+        let centre = (p + z * z / (2.0 * n)) / (1.0 + z * z / n);
+        let spread = z * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt() / (1.0 + z * z / n);
+        ((centre - spread).max(0.0), (centre + spread).min(1.0))
+    }
+}
+
+impl TournamentReport {
+    pub fn head_to_head_rate(&self, a: usize, b: usize) -> f64 {
+        rate(self.head_to_head[a][b], self.matches_per_pairing)
+    }
+
+    // Standings from the highest match win rate down, ties keeping entry order.
+    pub fn ranked(&self) -> Vec<&Standing> {
+        let mut ranked = self.standings.iter().collect::<Vec<&Standing>>();
+        ranked.sort_by(|a, b| b.win_rate().total_cmp(&a.win_rate()));
+        ranked
+    }
+}
+
+impl fmt::Display for TournamentReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} matches of {} rounds per pairing",
+            self.matches_per_pairing, self.rounds_per_match
+        )?;
+        writeln!(
+            f,
+            "{:<12} {:>7} {:>5} {:>5} {:>6} {:>8} {:>15} {:>9}",
+            "Player", "Matches", "Won", "Drawn", "Lost", "Win rate", "95% interval", "Round win"
+        )?;
+        for s in self.ranked() {
+            let (low, high) = s.win_rate_interval();
+            writeln!(
+                f,
+                "{:<12} {:>7} {:>5} {:>5} {:>6} {:>7.1}% {:>6.1}% - {:>5.1}% {:>8.1}%",
+                s.name,
+                s.matches,
+                s.wins,
+                s.draws,
+                s.losses,
+                100.0 * s.win_rate(),
+                100.0 * low,
+                100.0 * high,
+                100.0 * s.round_win_rate()
+            )?;
+        }
+        writeln!(f, "Head to head (row's match win rate against column):")?;
+        write!(f, "{:<12}", "")?;
+        for s in &self.standings {
+            write!(f, " {:>12}", s.name)?;
+        }
+        writeln!(f)?;
+        for (a, s) in self.standings.iter().enumerate() {
+            write!(f, "{:<12}", s.name)?;
+            for b in 0..self.standings.len() {
+                if a == b {
+                    write!(f, " {:>12}", "-")?;
+                } else {
+                    write!(f, " {:>11.1}%", 100.0 * self.head_to_head_rate(a, b))?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn rate(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+// SplitMix64: small, fast and good enough to shuffle bots' choices. Not for anything cryptographic.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(GOLDEN_GAMMA);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
@@ -0,0 +1,122 @@
+use crate::util::{parse_lines_into, read_input_to_str, AOCSolution, PuzzleParams, SResult};
+
+solution!(Year 2022 Day 20 => EncryptedFile);
+
+params!(EncryptedFileParams {
+    decryption_key: i64 = 811589153,
+    part_2_rounds: usize = 10,
+});
+
+pub struct EncryptedFile {
+    numbers: Vec<i64>,
+    params: EncryptedFileParams,
+}
+
+impl AOCSolution for EncryptedFile {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        self.try_part_1().unwrap_or_else(|e| e.to_string())
+    }
+
+    fn part_2(&mut self) -> String {
+        self.try_part_2().unwrap_or_else(|e| e.to_string())
+    }
+
+    fn try_part_1(&mut self) -> SResult<String> {
+        Ok(format!("{}", self.grove_coordinate_sum(1, 1)?))
+    }
+
+    fn try_part_2(&mut self) -> SResult<String> {
+        let key = self.params.decryption_key;
+        Ok(format!("{}", self.grove_coordinate_sum(key, self.params.part_2_rounds)?))
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
+}
+
+impl EncryptedFile {
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let numbers = parse_lines_into::<i64>(input_str)?;
+        Ok(Self {
+            numbers,
+            params: EncryptedFileParams::default(),
+        })
+    }
+
+    // Fails if the key makes the numbers, or the coordinates' sum, too large for an i64.
+    pub fn grove_coordinate_sum(&self, key: i64, rounds: usize) -> Result<i64, DecryptionError> {
+        let mixed = self.mix(key, rounds)?;
+        let zero = match mixed.iter().position(|n| *n == 0) {
+            Some(z) => z,
+            None => return Ok(0),
+        };
+        [1000, 2000, 3000]
+            .iter()
+            .try_fold(0i64, |sum, offset| sum.checked_add(mixed[(zero + offset) % mixed.len()]))
+            .ok_or(DecryptionError("The grove coordinates add up to more than fits in an i64"))
+    }
+
+    // Tracks where each original number currently sits, moving numbers in their original order.
+    fn mix(&self, key: i64, rounds: usize) -> Result<Vec<i64>, DecryptionError> {
+        let values = self
+            .numbers
+            .iter()
+            .map(|n| n.checked_mul(key))
+            .collect::<Option<Vec<i64>>>()
+            .ok_or(DecryptionError("The decryption key makes the numbers too large for an i64"))?;
+        let mut order = (0..values.len()).collect::<Vec<usize>>();
+        if values.len() < 2 {
+            return Ok(values);
+        }
+        // A number moving around the list passes every other number, so moves wrap at len - 1.
+        let cycle = values.len() as i64 - 1;
+        for _ in 0..rounds {
+            for (original, value) in values.iter().enumerate() {
+                let position = order.iter().position(|o| *o == original).unwrap();
+                order.remove(position);
+                let target = (position as i64 + value.rem_euclid(cycle)).rem_euclid(cycle) as usize;
+                order.insert(target, original);
+            }
+        }
+        Ok(order.iter().map(|o| values[*o]).collect())
+    }
+}
+
+custom_error!(DecryptionError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+        1
+        2
+        -3
+        3
+        -2
+        0
+        4
+        ";
+
+        let mut file = EncryptedFile::new_from_str(input_str).expect("Failed to parse numbers");
+        assert_eq!(file.part_1(), "3");
+        assert_eq!(file.part_2(), "1623178306");
+        file.params_mut().unwrap().set("decryption_key", &i64::MAX.to_string()).unwrap();
+        assert!(file.try_part_2().is_err());
+    }
+}
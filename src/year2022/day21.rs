@@ -0,0 +1,243 @@
+use crate::util::{parse_lines_into, read_input_to_str, AOCSolution, SResult};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+solution!(Year 2022 Day 21 => MonkeyMath);
+
+pub struct MonkeyMath {
+    jobs: HashMap<String, Job>,
+}
+
+impl AOCSolution for MonkeyMath {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        format!("{}", self.evaluate(Self::ROOT))
+    }
+
+    fn part_2(&mut self) -> String {
+        match self.solve_for_human() {
+            Some(value) => format!("{}", value),
+            None => "No solution".to_string(),
+        }
+    }
+}
+
+impl MonkeyMath {
+    const ROOT: &'static str = "root";
+    const HUMAN: &'static str = "humn";
+
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let monkeys = parse_lines_into::<Monkey>(input_str)?;
+        let math = Self {
+            jobs: monkeys.into_iter().map(|m| (m.name, m.job)).collect(),
+        };
+        if !math.jobs.contains_key(Self::ROOT) {
+            return Err(Box::new(MonkeyParseError("No root monkey")));
+        }
+        let listed = |name: &String| math.jobs.contains_key(name);
+        if math.jobs.values().any(|job| matches!(job, Job::Operation(l, _, r) if !listed(l) || !listed(r))) {
+            return Err(Box::new(MonkeyParseError("A monkey waits on a monkey that isn't listed")));
+        }
+        let mut done = HashSet::new();
+        if math.jobs.keys().any(|name| math.waits_on_itself(name, &mut HashSet::new(), &mut done)) {
+            return Err(Box::new(MonkeyParseError("A monkey waits on its own number")));
+        }
+        Ok(math)
+    }
+
+    pub fn evaluate(&self, name: &str) -> i64 {
+        match &self.jobs[name] {
+            Job::Number(n) => *n,
+            Job::Operation(left, op, right) => op.apply(self.evaluate(left), self.evaluate(right)),
+        }
+    }
+
+    // Root's two operands must match. Only one of them depends on the human, so
+    // evaluate the other side and undo each operation on the way down to the human.
+    pub fn solve_for_human(&self) -> Option<i64> {
+        let (left, right) = match self.jobs.get(Self::ROOT)? {
+            Job::Operation(left, _, right) => (left, right),
+            Job::Number(_) => return None,
+        };
+        let (mut unknown, mut target) = if self.depends_on_human(left) {
+            (left.as_str(), self.evaluate(right))
+        } else {
+            (right.as_str(), self.evaluate(left))
+        };
+
+        while unknown != Self::HUMAN {
+            let (left, op, right) = match self.jobs.get(unknown)? {
+                Job::Operation(left, op, right) => (left, op, right),
+                Job::Number(_) => return None,
+            };
+            if self.depends_on_human(left) {
+                target = op.solve_left(target, self.evaluate(right));
+                unknown = left;
+            } else {
+                target = op.solve_right(target, self.evaluate(left));
+                unknown = right;
+            }
+        }
+        Some(target)
+    }
+
+    // Whether following the monkeys `name` waits on leads back to one already on `path`. Monkeys in
+    // `done` have been followed to the end before.
+    fn waits_on_itself<'a>(&'a self, name: &'a str, path: &mut HashSet<&'a str>, done: &mut HashSet<&'a str>) -> bool {
+        if done.contains(name) {
+            return false;
+        }
+        if !path.insert(name) {
+            return true;
+        }
+        if let Job::Operation(left, _, right) = &self.jobs[name] {
+            if self.waits_on_itself(left, path, done) || self.waits_on_itself(right, path, done) {
+                return true;
+            }
+        }
+        path.remove(name);
+        done.insert(name);
+        false
+    }
+
+    fn depends_on_human(&self, name: &str) -> bool {
+        if name == Self::HUMAN {
+            return true;
+        }
+        match &self.jobs[name] {
+            Job::Number(_) => false,
+            Job::Operation(left, _, right) => self.depends_on_human(left) || self.depends_on_human(right),
+        }
+    }
+}
+
+struct Monkey {
+    name: String,
+    job: Job,
+}
+
+enum Job {
+    Number(i64),
+    Operation(String, Operator, String),
+}
+
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    pub fn apply(&self, left: i64, right: i64) -> i64 {
+        match self {
+            Operator::Add => left + right,
+            Operator::Subtract => left - right,
+            Operator::Multiply => left * right,
+            Operator::Divide => left / right,
+        }
+    }
+
+    // Finds `left` such that `left op right == target`.
+    pub fn solve_left(&self, target: i64, right: i64) -> i64 {
+        match self {
+            Operator::Add => target - right,
+            Operator::Subtract => target + right,
+            Operator::Multiply => target / right,
+            Operator::Divide => target * right,
+        }
+    }
+
+    // Finds `right` such that `left op right == target`.
+    pub fn solve_right(&self, target: i64, left: i64) -> i64 {
+        match self {
+            Operator::Add => target - left,
+            Operator::Subtract => left - target,
+            Operator::Multiply => target / left,
+            Operator::Divide => left / target,
+        }
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = MonkeyParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, job) = s
+            .split_once(": ")
+            .ok_or(MonkeyParseError("Invalid monkey, expected name: job"))?;
+        let job = job.parse::<Job>()?;
+        Ok(Self {
+            name: name.to_string(),
+            job,
+        })
+    }
+}
+
+impl FromStr for Job {
+    type Err = MonkeyParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(n) = s.parse::<i64>() {
+            return Ok(Job::Number(n));
+        }
+        let sections: [&str; 3] = s
+            .split(' ')
+            .collect::<Vec<&str>>()
+            .try_into()
+            .map_err(|_| MonkeyParseError("Invalid job, expected a number or an operation"))?;
+        let op = match sections[1] {
+            "+" => Operator::Add,
+            "-" => Operator::Subtract,
+            "*" => Operator::Multiply,
+            "/" => Operator::Divide,
+            _ => return Err(MonkeyParseError("Unknown operator")),
+        };
+        Ok(Job::Operation(sections[0].to_string(), op, sections[2].to_string()))
+    }
+}
+
+custom_error!(MonkeyParseError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+        root: pppw + sjmn
+        dbpl: 5
+        cczh: sllz + lgvd
+        zczc: 2
+        ptdq: humn - dvpt
+        dvpt: 3
+        lfqf: 4
+        humn: 5
+        ljgn: 2
+        sjmn: drzm * dbpl
+        sllz: 4
+        pppw: cczh / lfqf
+        lgvd: ljgn * ptdq
+        drzm: hmdt - zczc
+        hmdt: 32
+        ";
+
+        let mut math = MonkeyMath::new_from_str(input_str).expect("Failed to parse monkeys");
+        assert_eq!(math.part_1(), "152");
+        assert_eq!(math.part_2(), "301");
+
+        assert!(MonkeyMath::new_from_str("humn: 5").is_err());
+        assert!(MonkeyMath::new_from_str("root: humn + abcd\nhumn: 5").is_err());
+        assert!(MonkeyMath::new_from_str("root: humn + abcd\nhumn: 5\nabcd: root * humn").is_err());
+    }
+}
@@ -0,0 +1,352 @@
+use crate::util::{read_input_to_str, AOCSolution, SResult};
+use std::collections::{HashMap, VecDeque};
+
+solution!(Year 2022 Day 22 => MonkeyMap);
+
+pub struct MonkeyMap {
+    grid: Vec<Vec<u8>>,
+    path: Vec<Move>,
+}
+
+impl AOCSolution for MonkeyMap {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        format!("{}", self.follow_path(&FlatWrapping {}).password())
+    }
+
+    fn part_2(&mut self) -> String {
+        match CubeWrapping::fold(self) {
+            Some(cube) => format!("{}", self.follow_path(&cube).password()),
+            None => "Map does not fold into a cube".to_string(),
+        }
+    }
+}
+
+impl MonkeyMap {
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        // Leading spaces on the first row are significant, so don't trim.
+        let input_str = read_input_to_str(input_path, false)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let input_str = input_str.trim_start_matches(['\n', '\r']);
+        let (map_str, path_str) = input_str
+            .split_once("\n\n")
+            .ok_or(MapParseError("Expected a map and a path separated by a blank line"))?;
+        let grid = map_str
+            .lines()
+            .map(|l| l.trim_end().as_bytes().to_vec())
+            .collect::<Vec<Vec<u8>>>();
+        let path = Self::parse_path(path_str.trim())?;
+        Ok(Self { grid, path })
+    }
+
+    fn parse_path(s: &str) -> Result<Vec<Move>, MapParseError> {
+        let mut path = Vec::new();
+        let mut steps = String::new();
+        for c in s.chars() {
+            if c.is_ascii_digit() {
+                steps.push(c);
+                continue;
+            }
+            if !steps.is_empty() {
+                path.push(Move::Forward(steps.parse().map_err(|_| MapParseError("Invalid step count"))?));
+                steps.clear();
+            }
+            match c {
+                'L' => path.push(Move::TurnLeft),
+                'R' => path.push(Move::TurnRight),
+                _ => return Err(MapParseError("Invalid path, expected numbers, 'L' or 'R'")),
+            }
+        }
+        if !steps.is_empty() {
+            path.push(Move::Forward(steps.parse().map_err(|_| MapParseError("Invalid step count"))?));
+        }
+        Ok(path)
+    }
+
+    fn tile(&self, x: i64, y: i64) -> u8 {
+        if x < 0 || y < 0 {
+            return b' ';
+        }
+        self.grid
+            .get(y as usize)
+            .and_then(|row| row.get(x as usize))
+            .copied()
+            .unwrap_or(b' ')
+    }
+
+    fn width(&self) -> usize {
+        self.grid.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    fn start(&self) -> Position {
+        let x = self.grid[0].iter().position(|t| *t == b'.').unwrap_or(0);
+        Position { x, y: 0, facing: Facing::Right }
+    }
+
+    fn follow_path(&self, wrapping: &dyn Wrapping) -> Position {
+        let mut position = self.start();
+        for mv in self.path.iter() {
+            match mv {
+                Move::TurnLeft => position.facing = position.facing.turn_left(),
+                Move::TurnRight => position.facing = position.facing.turn_right(),
+                Move::Forward(steps) => {
+                    for _ in 0..*steps {
+                        let next = self.step(&position, wrapping);
+                        if self.tile(next.x as i64, next.y as i64) == b'#' {
+                            break;
+                        }
+                        position = next;
+                    }
+                }
+            }
+        }
+        position
+    }
+
+    fn step(&self, position: &Position, wrapping: &dyn Wrapping) -> Position {
+        let (dx, dy) = position.facing.delta();
+        let (x, y) = (position.x as i64 + dx, position.y as i64 + dy);
+        if self.tile(x, y) == b' ' {
+            return wrapping.wrap(self, position);
+        }
+        Position {
+            x: x as usize,
+            y: y as usize,
+            facing: position.facing,
+        }
+    }
+}
+
+// Decides where walking off the edge of the map leads.
+trait Wrapping {
+    fn wrap(&self, map: &MonkeyMap, from: &Position) -> Position;
+}
+
+struct FlatWrapping {}
+
+impl Wrapping for FlatWrapping {
+    fn wrap(&self, map: &MonkeyMap, from: &Position) -> Position {
+        let on_map = |x: usize, y: usize| map.tile(x as i64, y as i64) != b' ';
+        let (x, y) = match from.facing {
+            Facing::Right => ((0..map.width()).find(|x| on_map(*x, from.y)).unwrap(), from.y),
+            Facing::Left => ((0..map.width()).rev().find(|x| on_map(*x, from.y)).unwrap(), from.y),
+            Facing::Down => (from.x, (0..map.grid.len()).find(|y| on_map(from.x, *y)).unwrap()),
+            Facing::Up => (from.x, (0..map.grid.len()).rev().find(|y| on_map(from.x, *y)).unwrap()),
+        };
+        Position { x, y, facing: from.facing }
+    }
+}
+
+type Vector = [i32; 3];
+
+fn negate(v: Vector) -> Vector {
+    [-v[0], -v[1], -v[2]]
+}
+
+// One face of the folded cube: where it sits on the map, and which way its
+// outward normal and its map right/down directions point in 3D.
+struct CubeFace {
+    x0: usize,
+    y0: usize,
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+struct CubeWrapping {
+    size: usize,
+    faces: Vec<CubeFace>,
+    slots: HashMap<(usize, usize), usize>,
+}
+
+impl CubeWrapping {
+    // Folds the net by walking between neighbouring faces: crossing an edge
+    // tilts the face 90 degrees, so the normal becomes the direction walked.
+    pub fn fold(map: &MonkeyMap) -> Option<Self> {
+        let tiles = map.grid.iter().flatten().filter(|t| **t != b' ').count();
+        let size = (1..=tiles).find(|s| s * s * 6 >= tiles)?;
+        if size * size * 6 != tiles {
+            return None;
+        }
+
+        let mut faces = Vec::new();
+        let mut slots = HashMap::new();
+        let start = (map.start().x / size, 0);
+        let mut queue = VecDeque::new();
+        slots.insert(start, 0);
+        faces.push(CubeFace {
+            x0: start.0 * size,
+            y0: 0,
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        });
+        queue.push_back(start);
+        while let Some((fx, fy)) = queue.pop_front() {
+            let (n, r, d) = {
+                let face = &faces[slots[&(fx, fy)]];
+                (face.normal, face.right, face.down)
+            };
+            let neighbors = [
+                (fx as i64 + 1, fy as i64, r, negate(n), d),
+                (fx as i64 - 1, fy as i64, negate(r), n, d),
+                (fx as i64, fy as i64 + 1, d, r, negate(n)),
+                (fx as i64, fy as i64 - 1, negate(d), r, n),
+            ];
+            for (nx, ny, normal, right, down) in neighbors {
+                if nx < 0 || ny < 0 {
+                    continue;
+                }
+                let slot = (nx as usize, ny as usize);
+                let (x0, y0) = (slot.0 * size, slot.1 * size);
+                if slots.contains_key(&slot) || map.tile(x0 as i64, y0 as i64) == b' ' {
+                    continue;
+                }
+                slots.insert(slot, faces.len());
+                faces.push(CubeFace { x0, y0, normal, right, down });
+                queue.push_back(slot);
+            }
+        }
+        if faces.len() != 6 {
+            return None;
+        }
+        Some(Self { size, faces, slots })
+    }
+}
+
+impl Wrapping for CubeWrapping {
+    fn wrap(&self, _map: &MonkeyMap, from: &Position) -> Position {
+        let n = self.size;
+        let face = &self.faces[self.slots[&(from.x / n, from.y / n)]];
+        let (lx, ly) = (from.x - face.x0, from.y - face.y0);
+
+        // Direction walked in 3D, distance along the edge, and the direction that distance is measured in.
+        let (walked, offset, along) = match from.facing {
+            Facing::Right => (face.right, ly, face.down),
+            Facing::Left => (negate(face.right), ly, face.down),
+            Facing::Down => (face.down, lx, face.right),
+            Facing::Up => (negate(face.down), lx, face.right),
+        };
+        let target = self.faces.iter().find(|f| f.normal == walked).unwrap();
+
+        // After rolling over the edge we head into the cube, away from the old face.
+        let heading = negate(face.normal);
+        let facing = if heading == target.right {
+            Facing::Right
+        } else if heading == target.down {
+            Facing::Down
+        } else if heading == negate(target.right) {
+            Facing::Left
+        } else {
+            Facing::Up
+        };
+        let offset_on = |axis: Vector| if along == axis { offset } else { n - 1 - offset };
+        let (x, y) = match facing {
+            Facing::Right => (0, offset_on(target.down)),
+            Facing::Left => (n - 1, offset_on(target.down)),
+            Facing::Down => (offset_on(target.right), 0),
+            Facing::Up => (offset_on(target.right), n - 1),
+        };
+        Position {
+            x: target.x0 + x,
+            y: target.y0 + y,
+            facing,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Position {
+    x: usize,
+    y: usize,
+    facing: Facing,
+}
+
+impl Position {
+    pub fn password(&self) -> usize {
+        1000 * (self.y + 1) + 4 * (self.x + 1) + self.facing as usize
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Facing {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3,
+}
+
+impl Facing {
+    pub fn turn_right(&self) -> Facing {
+        match self {
+            Facing::Right => Facing::Down,
+            Facing::Down => Facing::Left,
+            Facing::Left => Facing::Up,
+            Facing::Up => Facing::Right,
+        }
+    }
+
+    pub fn turn_left(&self) -> Facing {
+        match self {
+            Facing::Right => Facing::Up,
+            Facing::Up => Facing::Left,
+            Facing::Left => Facing::Down,
+            Facing::Down => Facing::Right,
+        }
+    }
+
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Facing::Right => (1, 0),
+            Facing::Down => (0, 1),
+            Facing::Left => (-1, 0),
+            Facing::Up => (0, -1),
+        }
+    }
+}
+
+enum Move {
+    Forward(usize),
+    TurnLeft,
+    TurnRight,
+}
+
+custom_error!(MapParseError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+";
+
+        let mut map = MonkeyMap::new_from_str(input_str).expect("Failed to parse map");
+        assert_eq!(map.part_1(), "6032");
+        assert_eq!(map.part_2(), "5031");
+    }
+}
@@ -0,0 +1,161 @@
+use crate::util::{read_input_to_str, AOCSolution, PuzzleParams, SResult};
+use std::collections::{HashMap, HashSet};
+
+solution!(Year 2022 Day 23 => Grove);
+
+params!(GroveParams {
+    part_1_rounds: usize = 10,
+});
+
+pub struct Grove {
+    initial: HashSet<(i32, i32)>,
+    params: GroveParams,
+}
+
+impl AOCSolution for Grove {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        let mut diffusion = Diffusion::new(self.initial.clone());
+        for _ in 0..self.params.part_1_rounds {
+            diffusion.round();
+        }
+        format!("{}", diffusion.empty_ground())
+    }
+
+    fn part_2(&mut self) -> String {
+        let mut diffusion = Diffusion::new(self.initial.clone());
+        let mut rounds = 1;
+        while diffusion.round() {
+            rounds += 1;
+        }
+        format!("{}", rounds)
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
+}
+
+impl Grove {
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let mut initial = HashSet::new();
+        let lines = input_str.trim().lines().map(|l| l.trim());
+        for (y, line) in lines.enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        initial.insert((x as i32, y as i32));
+                    }
+                    '.' => {}
+                    _ => return Err(Box::new(GroveParseError("Invalid tile, expected '#' or '.'"))),
+                }
+            }
+        }
+        Ok(Self {
+            initial,
+            params: GroveParams::default(),
+        })
+    }
+}
+
+struct Diffusion {
+    elves: HashSet<(i32, i32)>,
+    first_direction: usize,
+}
+
+impl Diffusion {
+    // North, south, west, east: the cell moved to, then the three cells that must be empty.
+    const DIRECTIONS: [[(i32, i32); 4]; 4] = [
+        [(0, -1), (-1, -1), (0, -1), (1, -1)],
+        [(0, 1), (-1, 1), (0, 1), (1, 1)],
+        [(-1, 0), (-1, -1), (-1, 0), (-1, 1)],
+        [(1, 0), (1, -1), (1, 0), (1, 1)],
+    ];
+
+    pub fn new(elves: HashSet<(i32, i32)>) -> Self {
+        Self {
+            elves,
+            first_direction: 0,
+        }
+    }
+
+    // Returns false when no elf moved.
+    pub fn round(&mut self) -> bool {
+        let mut proposals: HashMap<(i32, i32), Vec<(i32, i32)>> = HashMap::new();
+        for elf in self.elves.iter() {
+            if let Some(target) = self.propose(elf) {
+                proposals.entry(target).or_default().push(*elf);
+            }
+        }
+
+        let mut moved = false;
+        for (target, proposers) in proposals {
+            if proposers.len() == 1 {
+                self.elves.remove(&proposers[0]);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+        self.first_direction = (self.first_direction + 1) % Self::DIRECTIONS.len();
+        moved
+    }
+
+    fn propose(&self, elf: &(i32, i32)) -> Option<(i32, i32)> {
+        let occupied = |(dx, dy): (i32, i32)| self.elves.contains(&(elf.0 + dx, elf.1 + dy));
+        let alone = (-1..=1)
+            .flat_map(|dx| (-1..=1).map(move |dy| (dx, dy)))
+            .filter(|d| *d != (0, 0))
+            .all(|d| !occupied(d));
+        if alone {
+            return None;
+        }
+        (0..Self::DIRECTIONS.len())
+            .map(|i| &Self::DIRECTIONS[(self.first_direction + i) % Self::DIRECTIONS.len()])
+            .find(|dir| dir[1..].iter().all(|d| !occupied(*d)))
+            .map(|dir| (elf.0 + dir[0].0, elf.1 + dir[0].1))
+    }
+
+    pub fn empty_ground(&self) -> usize {
+        let min_x = self.elves.iter().map(|e| e.0).min().unwrap_or(0);
+        let max_x = self.elves.iter().map(|e| e.0).max().unwrap_or(0);
+        let min_y = self.elves.iter().map(|e| e.1).min().unwrap_or(0);
+        let max_y = self.elves.iter().map(|e| e.1).max().unwrap_or(0);
+        let area = ((max_x - min_x + 1) * (max_y - min_y + 1)) as usize;
+        area - self.elves.len()
+    }
+}
+
+custom_error!(GroveParseError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+        ....#..
+        ..###.#
+        #...#.#
+        .#...##
+        #.###..
+        ##.#.##
+        .#..#..
+        ";
+
+        let mut grove = Grove::new_from_str(input_str).expect("Failed to parse grove");
+        assert_eq!(grove.part_1(), "110");
+        assert_eq!(grove.part_2(), "20");
+    }
+}
@@ -0,0 +1,178 @@
+use crate::util::{read_input_to_str, AOCSolution, SResult};
+use std::collections::HashSet;
+
+solution!(Year 2022 Day 24 => BlizzardBasin);
+
+pub struct BlizzardBasin {
+    // Interior of the valley only, without the surrounding walls.
+    cells: Vec<Vec<u8>>,
+    width: i32,
+    height: i32,
+    entrance: (i32, i32),
+    exit: (i32, i32),
+}
+
+impl AOCSolution for BlizzardBasin {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        self.try_part_1().unwrap_or_else(|e| e.to_string())
+    }
+
+    fn part_2(&mut self) -> String {
+        self.try_part_2().unwrap_or_else(|e| e.to_string())
+    }
+
+    fn try_part_1(&mut self) -> SResult<String> {
+        Ok(format!("{}", self.fastest_crossing(self.entrance, self.exit, 0)?))
+    }
+
+    fn try_part_2(&mut self) -> SResult<String> {
+        let there = self.fastest_crossing(self.entrance, self.exit, 0)?;
+        let back = self.fastest_crossing(self.exit, self.entrance, there)?;
+        let there_again = self.fastest_crossing(self.entrance, self.exit, back)?;
+        Ok(format!("{}", there_again))
+    }
+}
+
+impl BlizzardBasin {
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let rows = input_str
+            .trim()
+            .lines()
+            .map(|l| l.trim().as_bytes())
+            .collect::<Vec<&[u8]>>();
+        if rows.len() < 3 {
+            return Err(Box::new(BasinParseError("Valley too small")));
+        }
+        // Walls on both sides and at least one column between them, the same on every row.
+        if rows[0].len() < 3 || rows.iter().any(|row| row.len() != rows[0].len()) {
+            return Err(Box::new(BasinParseError("Valley rows must share a width of at least 3")));
+        }
+        // The only gap in a wall, which has to open onto the valley rather than a corner.
+        let gap = |row: &[u8]| {
+            let gaps = row.iter().enumerate().filter(|(_, c)| **c != b'#').map(|(x, _)| x).collect::<Vec<usize>>();
+            match gaps[..] {
+                [x] if row[x] == b'.' && x > 0 && x < row.len() - 1 => Some(x as i32 - 1),
+                _ => None,
+            }
+        };
+        let entrance_x = gap(rows[0]).ok_or(BasinParseError("No entrance in top wall"))?;
+        let exit_x = gap(rows[rows.len() - 1]).ok_or(BasinParseError("No exit in bottom wall"))?;
+        let cells = rows[1..rows.len() - 1]
+            .iter()
+            .map(|row| row[1..row.len() - 1].to_vec())
+            .collect::<Vec<Vec<u8>>>();
+        let width = cells[0].len() as i32;
+        let height = cells.len() as i32;
+        Ok(Self {
+            cells,
+            width,
+            height,
+            entrance: (entrance_x, -1),
+            exit: (exit_x, height),
+        })
+    }
+
+    // Breadth first over time: every position reachable at each minute.
+    // Returns the minute the goal is reached.
+    fn fastest_crossing(&self, from: (i32, i32), to: (i32, i32), start_time: usize) -> Result<usize, BasinCrossingError> {
+        // The blizzards are back where they started after this many minutes, so a position seen at the
+        // same point in the cycle has nothing new to offer. Once every one has been seen, there's no way across.
+        let period = lcm(self.width as usize, self.height as usize);
+        let mut seen = HashSet::new();
+        let mut frontier = vec![from];
+        let mut time = start_time;
+        while !frontier.is_empty() {
+            time += 1;
+            let mut next = Vec::new();
+            for (x, y) in frontier.iter() {
+                for (dx, dy) in [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    let candidate = (x + dx, y + dy);
+                    if candidate == to {
+                        return Ok(time);
+                    }
+                    if self.is_open(candidate, time) && seen.insert((candidate, time % period)) {
+                        next.push(candidate);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        Err(BasinCrossingError("There's no way across the valley"))
+    }
+
+    fn is_open(&self, (x, y): (i32, i32), time: usize) -> bool {
+        if (x, y) == self.entrance || (x, y) == self.exit {
+            return true;
+        }
+        if x < 0 || y < 0 || x >= self.width || y >= self.height {
+            return false;
+        }
+        // Blizzards wrap around, so look back along each direction for one that would be here now.
+        let t = time as i32;
+        let at = |x: i32, y: i32| self.cells[y.rem_euclid(self.height) as usize][x.rem_euclid(self.width) as usize];
+        at(x - t, y) != b'>' && at(x + t, y) != b'<' && at(x, y - t) != b'v' && at(x, y + t) != b'^'
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    a / x * b
+}
+
+custom_error!(BasinParseError);
+custom_error!(BasinCrossingError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+        #.######
+        #>>.<^<#
+        #.<..<<#
+        #>v.><>#
+        #<^v^^>#
+        ######.#
+        ";
+
+        let mut basin = BlizzardBasin::new_from_str(input_str).expect("Failed to parse valley");
+        assert_eq!(basin.part_1(), "18");
+        assert_eq!(basin.part_2(), "54");
+        assert!(BlizzardBasin::new_from_str("#.#\n#\n#.#").is_err());
+        assert!(BlizzardBasin::new_from_str("#.######\n#>>.<^<#\n#.<..<<\n######.#").is_err());
+        // Gaps in a wall's corner, or more than one gap.
+        assert!(BlizzardBasin::new_from_str("#.####\n#....#\n.#####").is_err());
+        assert!(BlizzardBasin::new_from_str("#..###\n#....#\n####.#").is_err());
+    }
+
+    #[test]
+    fn no_way_across() {
+        // A blizzard sits in the only cell below the entrance at every minute that the entrance can be left.
+        let input_str = "
+        #.#
+        #v#
+        #v#
+        #.#
+        ";
+        let mut basin = BlizzardBasin::new_from_str(input_str).expect("Failed to parse valley");
+        assert!(basin.try_part_1().is_err());
+        assert!(crate::solve(2022, 24, 2, input_str).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::util::{parse_lines_into, read_input_to_str, AOCSolution, SResult};
+use std::fmt::Display;
+use std::str::FromStr;
+
+solution!(Year 2022 Day 25 => FuelRequirements);
+
+pub struct FuelRequirements {
+    numbers: Vec<Snafu>,
+}
+
+impl AOCSolution for FuelRequirements {
+    fn load_from(input_file_path: &str) -> SResult<Box<Self>>
+    where
+        Self: Sized,
+    {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        format!("{}", self.total())
+    }
+
+    fn part_2(&mut self) -> String {
+        // There is no second puzzle on the last day.
+        "Merry Christmas!".to_string()
+    }
+}
+
+impl FuelRequirements {
+    pub fn new_from_file(input_path: &str) -> SResult<Self> {
+        let input_str = read_input_to_str(input_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> SResult<Self> {
+        let numbers = parse_lines_into::<Snafu>(input_str)?;
+        Ok(Self { numbers })
+    }
+
+    pub fn total(&self) -> Snafu {
+        Snafu(self.numbers.iter().map(|n| n.0).sum())
+    }
+}
+
+// Balanced base five, with digits 2, 1, 0, - (minus one) and = (minus two).
+#[derive(Debug, PartialEq, Eq)]
+pub struct Snafu(i64);
+
+impl FromStr for Snafu {
+    type Err = SnafuParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut value = 0;
+        for c in s.chars() {
+            let digit = match c {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => return Err(SnafuParseError("Invalid SNAFU digit")),
+            };
+            value = value * 5 + digit;
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 == 0 {
+            return f.write_str("0");
+        }
+        let mut digits = Vec::new();
+        let mut remaining = self.0;
+        while remaining != 0 {
+            // Shift by two so the remainder lands on a balanced digit.
+            let digit = (remaining + 2).rem_euclid(5) - 2;
+            digits.push(match digit {
+                2 => '2',
+                1 => '1',
+                0 => '0',
+                -1 => '-',
+                _ => '=',
+            });
+            remaining = (remaining - digit) / 5;
+        }
+        f.write_str(&digits.iter().rev().collect::<String>())
+    }
+}
+
+custom_error!(SnafuParseError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given() {
+        let input_str = "
+        1=-0-2
+        12111
+        2=0=
+        21
+        2=01
+        111
+        20012
+        112
+        1=-1=
+        1-12
+        12
+        1=
+        122
+        ";
+
+        let mut fuel = FuelRequirements::new_from_str(input_str).expect("Failed to parse numbers");
+        assert_eq!(fuel.total(), Snafu(4890));
+        assert_eq!(fuel.part_1(), "2=-1=0");
+        assert_eq!("1121-1110-1=0".parse::<Snafu>().unwrap(), Snafu(314159265));
+    }
+}
@@ -0,0 +1,159 @@
+use super::RucksackCollection;
+use std::fmt::Write;
+
+// How rucksacks are split into groups of elves that share a badge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupingPolicy {
+    // Consecutive groups of this size, as in the puzzle. Rucksacks left over at the end are reported.
+    Chunks(usize),
+    // Every run of this many consecutive rucksacks, so each rucksack is in several groups.
+    SlidingWindow(usize),
+    // Groups given as rucksack indices, counting from 0. Each rucksack can be in one group at most.
+    Explicit(Vec<Vec<usize>>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadgeGroup {
+    pub members: Vec<usize>,
+    // Every item the whole group carries, in the priority scheme's order. A well-formed group has
+    // exactly one.
+    pub candidates: Vec<char>,
+    // The badge's priority, if the group has one.
+    pub badge_priority: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadgeReport {
+    pub groups: Vec<BadgeGroup>,
+    // Rucksacks that didn't make up a full group.
+    pub leftover: Vec<usize>,
+}
+
+impl BadgeGroup {
+    // The group's badge, if exactly one item is common to all of it.
+    pub fn badge(&self) -> Option<char> {
+        match self.candidates[..] {
+            [badge] => Some(badge),
+            _ => None,
+        }
+    }
+}
+
+impl BadgeReport {
+    // Sum of the priorities of every group's badge. Groups without exactly one candidate don't count.
+    pub fn score(&self) -> u32 {
+        self.groups.iter().filter_map(|g| g.badge_priority).sum()
+    }
+
+    // Groups with no common item, or more than one.
+    pub fn problem_groups(&self) -> impl Iterator<Item = &BadgeGroup> {
+        self.groups.iter().filter(|g| g.badge().is_none())
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.leftover.is_empty() && self.problem_groups().next().is_none()
+    }
+}
+
+impl RucksackCollection {
+    pub fn find_badges(&self, policy: &GroupingPolicy) -> Result<BadgeReport, BadgeError> {
+        let count = self.rucksacks.len();
+        let (groups, leftover) = match policy {
+            GroupingPolicy::Chunks(0) | GroupingPolicy::SlidingWindow(0) => {
+                return Err(BadgeError("Badge groups need at least one rucksack."))
+            }
+            GroupingPolicy::Chunks(size) => {
+                let full = count - count % size;
+                let groups = (0..full)
+                    .step_by(*size)
+                    .map(|start| (start..start + size).collect())
+                    .collect();
+                (groups, (full..count).collect())
+            }
+            GroupingPolicy::SlidingWindow(size) if *size > count => (Vec::new(), (0..count).collect()),
+            GroupingPolicy::SlidingWindow(size) => {
+                let groups = (0..=count - size).map(|start| (start..start + size).collect()).collect();
+                (groups, Vec::new())
+            }
+            GroupingPolicy::Explicit(groups) => {
+                if groups.iter().any(|g| g.is_empty()) {
+                    return Err(BadgeError("Badge groups need at least one rucksack."));
+                }
+                let mut placed = vec![false; count];
+                for member in groups.iter().flatten() {
+                    match placed.get_mut(*member) {
+                        None => return Err(BadgeError("Badge group refers to a rucksack that doesn't exist.")),
+                        Some(true) => return Err(BadgeError("A rucksack can only be in one badge group.")),
+                        Some(seen) => *seen = true,
+                    }
+                }
+                let leftover = (0..count).filter(|i| !placed[*i]).collect();
+                (groups.clone(), leftover)
+            }
+        };
+
+        let groups = groups
+            .into_iter()
+            .map(|members: Vec<usize>| {
+                let candidates = members
+                    .iter()
+                    .map(|i| self.rucksacks[*i].items())
+                    .reduce(|common, items| common.intersection(&items))
+                    .unwrap_or_default();
+                let badge_priority = match candidates.len() {
+                    1 => Some(self.scheme.priority_sum(&candidates)),
+                    _ => None,
+                };
+                BadgeGroup {
+                    members,
+                    candidates: self.scheme.items(&candidates).collect(),
+                    badge_priority,
+                }
+            })
+            .collect();
+        Ok(BadgeReport { groups, leftover })
+    }
+
+    pub fn badge_policy(&self) -> GroupingPolicy {
+        if self.params.sliding_window {
+            GroupingPolicy::SlidingWindow(self.params.group_size)
+        } else {
+            GroupingPolicy::Chunks(self.params.group_size)
+        }
+    }
+
+    pub fn badge_report(&self) -> Option<String> {
+        let policy = self.badge_policy();
+        let mut report = String::new();
+        let badges = match self.find_badges(&policy) {
+            Ok(badges) => badges,
+            Err(e) => return Some(format!("{}\n", e)),
+        };
+        writeln!(report, "Grouping: {:?}", policy).ok()?;
+        writeln!(
+            report,
+            "{} groups, badge score {}",
+            badges.groups.len(),
+            badges.score()
+        )
+        .ok()?;
+        if !badges.leftover.is_empty() {
+            writeln!(report, "Not in a full group: rucksacks {:?}", badges.leftover).ok()?;
+        }
+        for group in badges.problem_groups() {
+            match group.candidates.len() {
+                0 => writeln!(report, "No common item in rucksacks {:?}", group.members).ok()?,
+                _ => writeln!(
+                    report,
+                    "Several candidate badges in rucksacks {:?}: {}",
+                    group.members,
+                    group.candidates.iter().collect::<String>()
+                )
+                .ok()?,
+            }
+        }
+        Some(report)
+    }
+}
+
+custom_error!(BadgeError);
@@ -0,0 +1,277 @@
+use super::item_set::MAX_SLOTS;
+use super::{ItemSet, PriorityScheme, Rucksack, RucksackCollection};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compartment {
+    Left,
+    Right,
+}
+
+// An item type packed in both compartments of one rucksack, with how many are in each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MisplacedItem {
+    pub item: char,
+    pub left: usize,
+    pub right: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackDuplicates {
+    pub rucksack: usize,
+    // In the priority scheme's order.
+    pub items: Vec<MisplacedItem>,
+}
+
+// How one item type is misplaced across the whole collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemTotal {
+    pub item: char,
+    // Rucksacks with this type in both compartments.
+    pub rucksacks: usize,
+    // Items of this type in those rucksacks, by compartment.
+    pub left: usize,
+    pub right: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateAnalysis {
+    // Only rucksacks with something misplaced.
+    pub rucksacks: Vec<RucksackDuplicates>,
+    // Only item types misplaced somewhere, in the priority scheme's order.
+    pub totals: Vec<ItemTotal>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemMove {
+    pub item: char,
+    pub count: usize,
+    pub to: Compartment,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackReorganization {
+    pub rucksack: usize,
+    pub moves: Vec<ItemMove>,
+    // The rucksack's new contents, in the puzzle's format.
+    pub packed: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReorganizationPlan {
+    // Only rucksacks that change.
+    pub rucksacks: Vec<RucksackReorganization>,
+    // Rucksacks whose item types can't be split into two equal halves. They're left as they are.
+    pub impossible: Vec<usize>,
+    // Every rucksack's contents once the plan is carried out, in order.
+    pub packed: Vec<String>,
+}
+
+impl DuplicateAnalysis {
+    pub fn misplaced_items(&self) -> usize {
+        self.totals.iter().map(|t| t.left + t.right).sum()
+    }
+}
+
+impl ReorganizationPlan {
+    pub fn moved_items(&self) -> usize {
+        self.rucksacks
+            .iter()
+            .flat_map(|r| &r.moves)
+            .map(|m| m.count)
+            .sum()
+    }
+
+    // The reorganized collection, ready to parse again.
+    pub fn to_input(&self) -> String {
+        self.packed.iter().map(|p| format!("{}\n", p)).collect()
+    }
+}
+
+// Item counts indexed by slot in the priority scheme.
+const SLOTS: usize = MAX_SLOTS + 1;
+
+impl RucksackCollection {
+    pub fn duplicate_analysis(&self) -> DuplicateAnalysis {
+        // (rucksacks, left, right) for each item type.
+        let mut counts = [(0, 0, 0); SLOTS];
+        let mut rucksacks = Vec::new();
+        for (index, rucksack) in self.rucksacks.iter().enumerate() {
+            let (left, right) = rucksack.item_counts(&self.scheme);
+            let items = rucksack
+                .shared_items()
+                .slots()
+                .map(|p| {
+                    counts[p] = (counts[p].0 + 1, counts[p].1 + left[p], counts[p].2 + right[p]);
+                    MisplacedItem {
+                        item: self.scheme.item(p),
+                        left: left[p],
+                        right: right[p],
+                    }
+                })
+                .collect::<Vec<MisplacedItem>>();
+            if !items.is_empty() {
+                rucksacks.push(RucksackDuplicates { rucksack: index, items });
+            }
+        }
+        let totals = self
+            .rucksacks
+            .iter()
+            .fold(ItemSet::new(), |all, r| all.union(&r.shared_items()))
+            .slots()
+            .map(|p| {
+                let (rucksacks, left, right) = counts[p];
+                ItemTotal {
+                    item: self.scheme.item(p),
+                    rucksacks,
+                    left,
+                    right,
+                }
+            })
+            .collect();
+        DuplicateAnalysis { rucksacks, totals }
+    }
+
+    // Moves items between compartments so no type is in both, keeping the compartments the same
+    // size and moving as few items as possible.
+    pub fn suggest_reorganization(&self) -> ReorganizationPlan {
+        let mut plan = ReorganizationPlan {
+            rucksacks: Vec::new(),
+            impossible: Vec::new(),
+            packed: Vec::with_capacity(self.rucksacks.len()),
+        };
+        for (index, rucksack) in self.rucksacks.iter().enumerate() {
+            if rucksack.shared_items().is_empty() {
+                plan.packed.push(rucksack.packed.clone());
+                continue;
+            }
+            match rucksack.reorganize(&self.scheme) {
+                Some((moves, packed)) => {
+                    plan.packed.push(packed.clone());
+                    plan.rucksacks.push(RucksackReorganization {
+                        rucksack: index,
+                        moves,
+                        packed,
+                    });
+                }
+                None => {
+                    plan.packed.push(rucksack.packed.clone());
+                    plan.impossible.push(index);
+                }
+            }
+        }
+        plan
+    }
+
+    pub fn duplicate_report(&self) -> Option<String> {
+        let analysis = self.duplicate_analysis();
+        let plan = self.suggest_reorganization();
+        let mut report = format!(
+            "{} misplaced items of {} types in {} rucksacks\n",
+            analysis.misplaced_items(),
+            analysis.totals.len(),
+            analysis.rucksacks.len()
+        );
+        let mut worst = analysis.totals.clone();
+        worst.sort_by_key(|t| std::cmp::Reverse(t.rucksacks));
+        let worst = worst
+            .iter()
+            .take(5)
+            .map(|t| format!("{} in {}", t.item, t.rucksacks))
+            .collect::<Vec<String>>();
+        if !worst.is_empty() {
+            report += &format!("Most often misplaced: {}\n", worst.join(", "));
+        }
+        report += &format!(
+            "Reorganizing moves {} items in {} rucksacks",
+            plan.moved_items(),
+            plan.rucksacks.len()
+        );
+        if !plan.impossible.is_empty() {
+            report += &format!(", {} can't be split evenly", plan.impossible.len());
+        }
+        report.push('\n');
+        Some(report)
+    }
+}
+
+impl Rucksack {
+    // Every item was checked against the scheme when the rucksack was parsed.
+    fn item_counts(&self, scheme: &PriorityScheme) -> ([usize; SLOTS], [usize; SLOTS]) {
+        let count = |items: &str| {
+            let mut counts = [0; SLOTS];
+            for item in items.chars() {
+                counts[scheme.slot(item).unwrap()] += 1;
+            }
+            counts
+        };
+        let (left, right) = self.compartments();
+        (count(left), count(right))
+    }
+
+    // Picks a side for every item type so that each side holds half the items, at the least cost
+    // in items moved. A subset sum over the 63 types at most, keeping one row of costs and a bit per
+    // type and sum for the choices, so memory grows with the rucksack's length by about a byte per
+    // eight items for each type.
+    fn reorganize(&self, scheme: &PriorityScheme) -> Option<(Vec<ItemMove>, String)> {
+        let (left, right) = self.item_counts(scheme);
+        let half = self.packed.chars().count() / 2;
+        let types = (1..SLOTS).filter(|p| left[*p] + right[*p] > 0).collect::<Vec<usize>>();
+
+        // cost[s]: fewest moves placing the types so far with s items on the left. Bit s of
+        // to_left[k] is set when the best way to reach s puts type k on the left.
+        let words = half / 64 + 1;
+        let mut cost = vec![None; half + 1];
+        let mut to_left = vec![vec![0u64; words]; types.len()];
+        cost[0] = Some(0);
+        for (k, p) in types.iter().enumerate() {
+            let size = left[*p] + right[*p];
+            // Downwards, so cost[s - size] still holds the previous types' cost.
+            for s in (0..=half).rev() {
+                let stays_right = cost[s].map(|c: usize| c + left[*p]);
+                let goes_left = s.checked_sub(size).and_then(|from| cost[from]).map(|c| c + right[*p]);
+                if goes_left.is_some_and(|l| stays_right.is_none_or(|r| l < r)) {
+                    to_left[k][s / 64] |= 1 << (s % 64);
+                    cost[s] = goes_left;
+                } else {
+                    cost[s] = stays_right;
+                }
+            }
+        }
+        // No way to fill exactly half the rucksack with whole types.
+        cost[half]?;
+
+        let mut on_left = [false; SLOTS];
+        let mut s = half;
+        for (k, p) in types.iter().enumerate().rev() {
+            if to_left[k][s / 64] & (1 << (s % 64)) != 0 {
+                on_left[*p] = true;
+                s -= left[*p] + right[*p];
+            }
+        }
+
+        let mut moves = Vec::new();
+        for p in types {
+            let item = scheme.item(p);
+            match on_left[p] {
+                true if right[p] > 0 => moves.push(ItemMove {
+                    item,
+                    count: right[p],
+                    to: Compartment::Left,
+                }),
+                false if left[p] > 0 => moves.push(ItemMove {
+                    item,
+                    count: left[p],
+                    to: Compartment::Right,
+                }),
+                _ => {}
+            }
+        }
+        let side = |want_left: bool| {
+            self.packed
+                .chars()
+                .filter(|c| on_left[scheme.slot(*c).unwrap()] == want_left)
+                .collect::<String>()
+        };
+        Some((moves, side(true) + &side(false)))
+    }
+}
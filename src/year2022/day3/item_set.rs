@@ -0,0 +1,55 @@
+// A set of rucksack items as a bitmask over a `PriorityScheme`'s slots, which run from 1 to
+// `MAX_SLOTS`. Bit 0 is never set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct ItemSet(u64);
+
+pub const MAX_SLOTS: usize = 63;
+
+impl ItemSet {
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    pub fn insert(&mut self, slot: usize) {
+        debug_assert!((1..=MAX_SLOTS).contains(&slot));
+        self.0 |= 1 << slot;
+    }
+
+    pub fn contains(&self, slot: usize) -> bool {
+        slot <= MAX_SLOTS && self.0 & (1 << slot) != 0
+    }
+
+    pub fn union(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    // The lowest slot in the set.
+    pub fn first(&self) -> Option<usize> {
+        self.slots().next()
+    }
+
+    // Slots in the set, lowest first.
+    pub fn slots(&self) -> impl Iterator<Item = usize> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let slot = bits.trailing_zeros() as usize;
+            bits &= bits - 1;
+            Some(slot)
+        })
+    }
+}
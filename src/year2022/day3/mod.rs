@@ -0,0 +1,342 @@
+use crate::util::*;
+
+mod badges;
+mod duplicates;
+mod item_set;
+mod partitions;
+mod priority;
+
+pub use badges::{BadgeError, BadgeGroup, BadgeReport, GroupingPolicy};
+pub use duplicates::{
+    Compartment, DuplicateAnalysis, ItemMove, ItemTotal, MisplacedItem, ReorganizationPlan, RucksackDuplicates,
+    RucksackReorganization,
+};
+pub use item_set::ItemSet;
+pub use partitions::{BadgePartition, PartitionError, PartitionSearch};
+pub use priority::{PriorityScheme, PrioritySchemeError};
+
+solution!(Year 2022 Day 3 => RucksackCollection);
+
+params!(RucksackParams {
+    group_size: usize = 3,
+    sliding_window: bool = false,
+});
+
+pub struct RucksackCollection {
+    rucksacks: Vec<Rucksack>,
+    scheme: PriorityScheme,
+    params: RucksackParams,
+}
+
+impl AOCSolution for RucksackCollection {
+    fn load_from(input_file_path: &str) -> Result<Box<Self>, Box<dyn std::error::Error>>
+        where
+            Self: Sized {
+        Ok(Box::new(Self::new_from_file(input_file_path)?))
+    }
+
+    fn part_1(&mut self) -> String {
+        format!("{}", self.score())
+    }
+
+    fn part_2(&mut self) -> String {
+        match self.find_badges(&self.badge_policy()) {
+            Ok(badges) => format!("{}", badges.score()),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
+
+    fn report(&mut self) -> Option<String> {
+        Some(self.duplicate_report()? + &self.badge_report()?)
+    }
+}
+
+impl RucksackCollection {
+    pub fn new_from_file(input_file_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let input_str = read_input_to_str(input_file_path, true)?;
+        Self::new_from_str(&input_str)
+    }
+
+    pub fn new_from_str(input_str: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::new_from_str_with_scheme(input_str, PriorityScheme::default())
+    }
+
+    // Rucksacks holding items from another scheme. Any item outside it is an error.
+    pub fn new_from_str_with_scheme(
+        input_str: &str,
+        scheme: PriorityScheme,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let rucksacks = input_str
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| Rucksack::parse(line, &scheme))
+            .collect::<Result<Vec<Rucksack>, RucksackParseError>>()?;
+        Ok(Self {
+            rucksacks,
+            scheme,
+            params: RucksackParams::default(),
+        })
+    }
+
+    pub fn scheme(&self) -> &PriorityScheme {
+        &self.scheme
+    }
+
+    pub fn score(&self) -> u32 {
+        self.rucksacks
+            .iter()
+            .filter_map(|r| r.shared_items().first())
+            .map(|slot| self.scheme.slot_priority(slot))
+            .sum()
+    }
+
+    // Groups that don't have exactly one badge, or an invalid group size, score nothing; see `find_badges`.
+    pub fn badge_score(&self) -> u32 {
+        self.find_badges(&self.badge_policy()).map_or(0, |badges| badges.score())
+    }
+}
+
+struct Rucksack {
+    // Everything in the rucksack as listed, the first half being the left compartment.
+    packed: String,
+    left: ItemSet,
+    right: ItemSet
+}
+
+impl Rucksack {
+    fn parse(s: &str, scheme: &PriorityScheme) -> Result<Self, RucksackParseError> {
+        if !s.chars().count().is_multiple_of(2) {
+            return Err(RucksackParseError("Invalid string length for rucksack entry."));
+        }
+        let (left_str, right_str) = halve(s);
+        let parse = |items: &str| {
+            scheme
+                .item_set(items)
+                .map_err(|_| RucksackParseError("Rucksack item isn't in the priority scheme."))
+        };
+        let (left, right) = (parse(left_str)?, parse(right_str)?);
+        Ok(Self {
+            packed: s.to_string(),
+            left,
+            right
+        })
+    }
+
+    // Items packed in both compartments.
+    pub fn shared_items(&self) -> ItemSet {
+        self.left.intersection(&self.right)
+    }
+
+    pub fn items(&self) -> ItemSet {
+        self.left.union(&self.right)
+    }
+
+    pub fn compartments(&self) -> (&str, &str) {
+        halve(&self.packed)
+    }
+}
+
+// Splits after half the characters, not half the bytes, so multibyte items stay whole.
+fn halve(s: &str) -> (&str, &str) {
+    let idx = s
+        .char_indices()
+        .nth(s.chars().count() / 2)
+        .map_or(s.len(), |(i, _)| i);
+    s.split_at(idx)
+}
+
+custom_error!(RucksackParseError);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given_test() {
+        let input = "
+        vJrwpWtwJgWrhcsFMMfFFhFp
+        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+        PmmdzqPrVvPwwTWBwg
+        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+        ttgJtRGJQctTZtZT
+        CrZsJsPPZsGzwwsLwLmpwMDw
+        ";
+
+        let coll = RucksackCollection::new_from_str(input).expect("Failed to parse rucksack");
+        let score = coll.score();
+        assert_eq!(score, 157);
+        let badge_score = coll.badge_score();
+        assert_eq!(badge_score, 70);
+    }
+
+    #[test]
+    fn item_sets() {
+        let aoc = PriorityScheme::aoc();
+        let left = aoc.item_set("vJrwpWtwJgWr").unwrap();
+        let right = aoc.item_set("hcsFMMfFFhFp").unwrap();
+        assert_eq!(left.len(), 8);
+        assert!(left.contains(aoc.slot('W').unwrap()) && !left.contains(aoc.slot('h').unwrap()));
+        assert_eq!(aoc.slot('1'), None);
+        assert_eq!(aoc.items(&left.intersection(&right)).collect::<String>(), "p");
+        assert_eq!(left.union(&right).len(), 14);
+        assert_eq!(aoc.item_set("aZzA").unwrap().slots().collect::<Vec<usize>>(), vec![1, 26, 27, 52]);
+        assert_eq!(aoc.priority_sum(&aoc.item_set("zA").unwrap()), 53);
+        assert!(ItemSet::new().is_empty() && ItemSet::new().first().is_none());
+        assert!(aoc.item_set("ab-c").is_err());
+        assert!(RucksackCollection::new_from_str("aébc").is_err());
+    }
+
+    #[test]
+    fn priority_schemes() {
+        assert_eq!(PriorityScheme::aoc().priority('a'), Some(1));
+        assert_eq!(PriorityScheme::aoc().priority('Z'), Some(52));
+        assert_eq!(PriorityScheme::aoc().priority('é'), None);
+
+        // Multibyte items split by character: "αβ" and "γα".
+        let greek = PriorityScheme::from_order("αβγδ").unwrap();
+        let coll = RucksackCollection::new_from_str_with_scheme("αβγα\nδγβγ", greek).unwrap();
+        assert_eq!(coll.score(), 1 + 3);
+        assert_eq!(coll.badge_score(), 0);
+        assert!(RucksackCollection::new_from_str_with_scheme("αa", PriorityScheme::from_order("αβ").unwrap()).is_err());
+
+        let weighted = PriorityScheme::with_priorities(&[('🍎', 10), ('🍌', 5), ('x', 10)]).unwrap();
+        assert_eq!((weighted.len(), weighted.priority('🍌')), (3, Some(5)));
+        let coll = RucksackCollection::new_from_str_with_scheme("🍎🍌x🍎", weighted.clone()).unwrap();
+        assert_eq!(coll.score(), 10);
+        assert_eq!(coll.duplicate_analysis().totals[0].item, '🍎');
+        assert_eq!(coll.suggest_reorganization().packed, vec!["🍎🍎🍌x"]);
+
+        assert!(PriorityScheme::from_order("").is_err());
+        assert!(PriorityScheme::from_order("abca").is_err());
+        assert!(PriorityScheme::from_order("a b").is_err());
+        assert!(PriorityScheme::from_order(&"α".repeat(2)).is_err());
+        let many = (0..64).map(|i| char::from_u32(0x4e00 + i).unwrap()).collect::<String>();
+        assert!(PriorityScheme::from_order(&many).is_err());
+        assert_eq!(PriorityScheme::from_order(&many[3..]).unwrap().len(), 63);
+    }
+
+    // The puzzle's example, for the tests below.
+    const EXAMPLE: &str = "
+        vJrwpWtwJgWrhcsFMMfFFhFp
+        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+        PmmdzqPrVvPwwTWBwg
+        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+        ttgJtRGJQctTZtZT
+        CrZsJsPPZsGzwwsLwLmpwMDw
+        ";
+
+    #[test]
+    fn badge_groups() {
+        let coll = RucksackCollection::new_from_str(EXAMPLE).expect("Failed to parse rucksack");
+        let badges = |policy: GroupingPolicy| coll.find_badges(&policy).unwrap();
+        let candidates = |report: &BadgeReport| {
+            report
+                .groups
+                .iter()
+                .map(|g| g.candidates.iter().collect::<String>())
+                .collect::<Vec<String>>()
+        };
+
+        let puzzle = badges(GroupingPolicy::Chunks(3));
+        assert_eq!(puzzle.score(), 70);
+        assert!(puzzle.is_clean());
+        assert_eq!(puzzle.groups[1].members, vec![3, 4, 5]);
+        assert_eq!(puzzle.groups[1].badge(), Some('Z'));
+
+        // Two left over, and the one full group has nothing in common.
+        let fours = badges(GroupingPolicy::Chunks(4));
+        assert_eq!(candidates(&fours), vec![""]);
+        assert_eq!(fours.leftover, vec![4, 5]);
+        assert_eq!((fours.score(), fours.problem_groups().count()), (0, 1));
+
+        let pairs = badges(GroupingPolicy::Chunks(2));
+        assert_eq!(candidates(&pairs), vec!["frsFM", "qvwBT", "GJZ"]);
+        assert_eq!(pairs.problem_groups().count(), 3);
+
+        let sliding = badges(GroupingPolicy::SlidingWindow(3));
+        assert_eq!(candidates(&sliding), vec!["r", "q", "T", "Z"]);
+        assert_eq!(sliding.score(), 18 + 17 + 46 + 52);
+        assert!(badges(GroupingPolicy::SlidingWindow(7)).groups.is_empty());
+
+        let explicit = badges(GroupingPolicy::Explicit(vec![vec![5, 4, 3], vec![0, 2]]));
+        assert_eq!(candidates(&explicit), vec!["Z", "grvwW"]);
+        assert_eq!(explicit.leftover, vec![1]);
+        assert_eq!(explicit.score(), 52);
+
+        assert!(coll.find_badges(&GroupingPolicy::Chunks(0)).is_err());
+        assert!(coll.find_badges(&GroupingPolicy::Explicit(vec![vec![0, 6]])).is_err());
+        assert!(coll.find_badges(&GroupingPolicy::Explicit(vec![vec![]])).is_err());
+        assert!(coll.find_badges(&GroupingPolicy::Explicit(vec![vec![0, 0, 0]])).is_err());
+        assert!(coll.find_badges(&GroupingPolicy::Explicit(vec![vec![5, 4, 3], vec![0, 3]])).is_err());
+    }
+
+    #[test]
+    fn badge_partitions() {
+        let coll = RucksackCollection::new_from_str(EXAMPLE).expect("Failed to parse rucksack");
+        let groups = |partition: &BadgePartition| {
+            partition
+                .groups
+                .iter()
+                .map(|g| (g.members.clone(), g.badge().unwrap()))
+                .collect::<Vec<(Vec<usize>, char)>>()
+        };
+
+        // The puzzle's own grouping, and one other.
+        let threes = coll.discover_badge_groups(3, None).unwrap();
+        assert!(threes.exhaustive);
+        assert_eq!(threes.partitions.len(), 2);
+        assert_eq!(groups(&threes.partitions[0]), vec![(vec![0, 1, 2], 'r'), (vec![3, 4, 5], 'Z')]);
+        assert_eq!(groups(&threes.partitions[1]), vec![(vec![0, 4, 5], 'J'), (vec![1, 2, 3], 'q')]);
+        assert_eq!(threes.partitions.iter().map(|p| p.score()).collect::<Vec<u32>>(), vec![70, 53]);
+        for partition in &threes.partitions {
+            assert!(coll.find_badges(&partition.policy()).unwrap().is_clean());
+        }
+
+        let limited = coll.discover_badge_groups(3, Some(1)).unwrap();
+        assert!(!limited.exhaustive && !limited.proves_none());
+        assert_eq!(limited.partitions, threes.partitions[..1]);
+        // A limit reached on the last grouping there is still covers them all.
+        assert_eq!(coll.discover_badge_groups(3, Some(2)).unwrap(), threes);
+
+        // Every pair shares several items or none, and six rucksacks don't split into fours.
+        assert!(coll.discover_badge_groups(2, None).unwrap().proves_none());
+        assert!(coll.discover_badge_groups(4, None).unwrap().proves_none());
+        assert!(coll.discover_badge_groups(0, None).is_err());
+        assert!(coll.discover_badge_groups(3, Some(0)).is_err());
+    }
+
+    #[test]
+    fn duplicates() {
+        let input = format!("{}abcdbcda\naaab\n", EXAMPLE);
+        let coll = RucksackCollection::new_from_str(&input).expect("Failed to parse rucksack");
+        let analysis = coll.duplicate_analysis();
+        assert_eq!(analysis.rucksacks.len(), 8);
+        assert_eq!(analysis.rucksacks[2].items, vec![MisplacedItem { item: 'P', left: 2, right: 1 }]);
+        assert_eq!(analysis.rucksacks[6].items.iter().map(|m| m.item).collect::<String>(), "abcd");
+        assert_eq!(analysis.totals[0], ItemTotal { item: 'a', rucksacks: 2, left: 3, right: 2 });
+        assert_eq!(analysis.totals.len(), 10);
+        assert_eq!(analysis.misplaced_items(), 33);
+
+        // Three a's and a b can't be split into halves without an a on each side.
+        let plan = coll.suggest_reorganization();
+        assert_eq!(plan.impossible, vec![7]);
+        assert_eq!(plan.rucksacks.len(), 7);
+        assert_eq!(plan.rucksacks[0].moves.len(), 2);
+        assert_eq!(plan.rucksacks[6].packed, "abbacdcd");
+        assert_eq!(plan.moved_items(), 22);
+        let reorganized = RucksackCollection::new_from_str(&plan.to_input()).unwrap();
+        assert_eq!(reorganized.score(), 1);
+        assert_eq!(reorganized.duplicate_analysis().rucksacks[0].rucksack, 7);
+
+        // Long rucksacks split the same way, every type moving half its items.
+        let long = format!("{}{}{}\n", "a".repeat(500), "b".repeat(1000), "a".repeat(500));
+        let plan = RucksackCollection::new_from_str(&long).unwrap().suggest_reorganization();
+        assert_eq!(plan.moved_items(), 1000);
+        assert_eq!(plan.packed[0], "a".repeat(1000) + &"b".repeat(1000));
+    }
+}
@@ -0,0 +1,225 @@
+use super::{BadgeGroup, GroupingPolicy, ItemSet, PriorityScheme, RucksackCollection};
+
+// One way to split every rucksack into groups that each share exactly one item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadgePartition {
+    // Ordered by each group's first member, members in input order.
+    pub groups: Vec<BadgeGroup>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartitionSearch {
+    pub partitions: Vec<BadgePartition>,
+    // Whether every possible grouping was tried, rather than stopping at the limit.
+    pub exhaustive: bool,
+}
+
+impl BadgePartition {
+    pub fn score(&self) -> u32 {
+        self.groups.iter().filter_map(|g| g.badge_priority).sum()
+    }
+
+    // The grouping as a policy for `find_badges`.
+    pub fn policy(&self) -> GroupingPolicy {
+        GroupingPolicy::Explicit(self.groups.iter().map(|g| g.members.clone()).collect())
+    }
+}
+
+impl PartitionSearch {
+    // Every grouping was tried and none works.
+    pub fn proves_none(&self) -> bool {
+        self.exhaustive && self.partitions.is_empty()
+    }
+}
+
+// An exact cover search, Knuth's Algorithm X: every group with exactly one common item is an option,
+// and each rucksack must be covered by exactly one of them. It always branches on the rucksack with
+// the fewest options left and backtracks as soon as any rucksack has none, which finds groupings of
+// the puzzle's input quickly. Proving that none exist can still take exponential time in general.
+struct Search<'a> {
+    group_size: usize,
+    limit: Option<usize>,
+    // Members of every option, `group_size` at a time, and each option's badge slot.
+    members: Vec<usize>,
+    badges: Vec<usize>,
+    // Options covering each rucksack, and how many of them are still open.
+    options: Vec<Vec<usize>>,
+    open: Vec<usize>,
+    // Open options have no blocks: each chosen option blocks every option sharing a rucksack with it.
+    blocks: Vec<usize>,
+    covered: Vec<bool>,
+    chosen: Vec<usize>,
+    found: Vec<Vec<usize>>,
+    // Cleared when a grouping is found beyond the limit.
+    exhaustive: bool,
+    scheme: &'a PriorityScheme,
+}
+
+impl RucksackCollection {
+    // Groups the rucksacks without regard to their order, finding up to `limit` partitions into
+    // groups of `group_size` that each have exactly one common item, or all of them for `None`.
+    pub fn discover_badge_groups(
+        &self,
+        group_size: usize,
+        limit: Option<usize>,
+    ) -> Result<PartitionSearch, PartitionError> {
+        if group_size == 0 {
+            return Err(PartitionError("Badge groups need at least one rucksack."));
+        }
+        if limit == Some(0) {
+            return Err(PartitionError("The partition limit must be at least one."));
+        }
+        let count = self.rucksacks.len();
+        let mut search = Search {
+            group_size,
+            limit,
+            members: Vec::new(),
+            badges: Vec::new(),
+            options: vec![Vec::new(); count],
+            open: vec![0; count],
+            blocks: Vec::new(),
+            covered: vec![false; count],
+            chosen: Vec::new(),
+            found: Vec::new(),
+            exhaustive: true,
+            scheme: &self.scheme,
+        };
+        if !count.is_multiple_of(group_size) {
+            return Ok(PartitionSearch { partitions: Vec::new(), exhaustive: true });
+        }
+        let items = self.rucksacks.iter().map(|r| r.items()).collect::<Vec<ItemSet>>();
+        for first in 0..count {
+            for badge in items[first].slots() {
+                let candidates = (first + 1..count)
+                    .filter(|i| items[*i].contains(badge))
+                    .collect::<Vec<usize>>();
+                search.add_options(&items, &candidates, 0, items[first], badge, &mut vec![first]);
+            }
+        }
+        search.open = search.options.iter().map(|o| o.len()).collect();
+        search.blocks = vec![0; search.badges.len()];
+        search.run();
+        let partitions = search.found.iter().map(|p| search.partition(p)).collect();
+        Ok(PartitionSearch { partitions, exhaustive: search.exhaustive })
+    }
+}
+
+impl Search<'_> {
+    // Every group of `first` and members from `candidates[from..]` whose one common item is the badge.
+    fn add_options(
+        &mut self,
+        items: &[ItemSet],
+        candidates: &[usize],
+        from: usize,
+        common: ItemSet,
+        badge: usize,
+        group: &mut Vec<usize>,
+    ) {
+        if group.len() == self.group_size {
+            if common.len() == 1 {
+                for member in group.iter() {
+                    self.options[*member].push(self.badges.len());
+                }
+                self.members.extend_from_slice(group);
+                self.badges.push(badge);
+            }
+            return;
+        }
+        let needed = self.group_size - group.len();
+        for n in from..candidates.len() {
+            if candidates.len() - n < needed {
+                break;
+            }
+            group.push(candidates[n]);
+            self.add_options(items, candidates, n + 1, common.intersection(&items[candidates[n]]), badge, group);
+            group.pop();
+        }
+    }
+
+    fn option_members(&self, option: usize) -> &[usize] {
+        &self.members[option * self.group_size..(option + 1) * self.group_size]
+    }
+
+    // False once a grouping turns up beyond the limit.
+    fn run(&mut self) -> bool {
+        let Some(rucksack) = (0..self.covered.len())
+            .filter(|r| !self.covered[*r])
+            .min_by_key(|r| self.open[*r])
+        else {
+            // The search carries on past the limit only to learn whether any grouping was left out.
+            if self.limit.is_some_and(|limit| self.found.len() == limit) {
+                self.exhaustive = false;
+                return false;
+            }
+            let mut partition = self.chosen.clone();
+            partition.sort_by_key(|o| self.members[o * self.group_size]);
+            self.found.push(partition);
+            return true;
+        };
+        for i in 0..self.options[rucksack].len() {
+            let option = self.options[rucksack][i];
+            if self.blocks[option] != 0 {
+                continue;
+            }
+            self.choose(option, true);
+            let keep_going = self.run();
+            self.choose(option, false);
+            if !keep_going {
+                return false;
+            }
+        }
+        true
+    }
+
+    // Takes an option, or puts it back, blocking or unblocking everything that overlaps it.
+    fn choose(&mut self, option: usize, take: bool) {
+        for n in 0..self.group_size {
+            let member = self.members[option * self.group_size + n];
+            self.covered[member] = take;
+            for i in 0..self.options[member].len() {
+                let other = self.options[member][i];
+                if take {
+                    self.blocks[other] += 1;
+                    if self.blocks[other] == 1 {
+                        self.set_open(other, false);
+                    }
+                } else {
+                    self.blocks[other] -= 1;
+                    if self.blocks[other] == 0 {
+                        self.set_open(other, true);
+                    }
+                }
+            }
+        }
+        if take {
+            self.chosen.push(option);
+        } else {
+            self.chosen.pop();
+        }
+    }
+
+    fn set_open(&mut self, option: usize, open: bool) {
+        for n in 0..self.group_size {
+            let member = self.members[option * self.group_size + n];
+            if open {
+                self.open[member] += 1;
+            } else {
+                self.open[member] -= 1;
+            }
+        }
+    }
+
+    fn partition(&self, options: &[usize]) -> BadgePartition {
+        let groups = options
+            .iter()
+            .map(|option| BadgeGroup {
+                members: self.option_members(*option).to_vec(),
+                candidates: vec![self.scheme.item(self.badges[*option])],
+                badge_priority: Some(self.scheme.slot_priority(self.badges[*option])),
+            })
+            .collect();
+        BadgePartition { groups }
+    }
+}
+
+custom_error!(PartitionError);
@@ -0,0 +1,116 @@
+use super::item_set::{ItemSet, MAX_SLOTS};
+use std::collections::HashMap;
+
+// Which items can go in a rucksack and what each is worth. Items are numbered into slots in the
+// order the scheme lists them, and any character can be an item except whitespace, which separates
+// rucksacks. A scheme holds at most `MAX_SLOTS` items, so an `ItemSet` fits in a `u64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityScheme {
+    // items[slot - 1] and priorities[slot - 1] describe each slot.
+    items: Vec<char>,
+    priorities: Vec<u32>,
+    // Slots of ASCII items, 0 for none, so the puzzle's own items skip the map.
+    ascii: [u8; 128],
+    others: HashMap<char, u8>,
+}
+
+impl Default for PriorityScheme {
+    fn default() -> Self {
+        Self::aoc()
+    }
+}
+
+impl PriorityScheme {
+    // The puzzle's scheme: a-z are worth 1 to 26 and A-Z 27 to 52.
+    pub fn aoc() -> Self {
+        Self::from_order("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ").expect("The puzzle's scheme is valid")
+    }
+
+    // Items worth 1, 2, 3, ... in the order given, e.g. "αβγδ".
+    pub fn from_order(order: &str) -> Result<Self, PrioritySchemeError> {
+        let items = order.chars().enumerate().map(|(i, c)| (c, i as u32 + 1)).collect::<Vec<(char, u32)>>();
+        Self::with_priorities(&items)
+    }
+
+    // Items with any priorities, which needn't be distinct or in order. Slots follow the order given.
+    pub fn with_priorities(items: &[(char, u32)]) -> Result<Self, PrioritySchemeError> {
+        if items.is_empty() {
+            return Err(PrioritySchemeError("A priority scheme needs at least one item."));
+        }
+        if items.len() > MAX_SLOTS {
+            return Err(PrioritySchemeError("A priority scheme can have at most 63 items."));
+        }
+        let mut scheme = Self {
+            items: Vec::with_capacity(items.len()),
+            priorities: Vec::with_capacity(items.len()),
+            ascii: [0; 128],
+            others: HashMap::new(),
+        };
+        for (item, priority) in items {
+            if item.is_whitespace() {
+                return Err(PrioritySchemeError("Whitespace can't be a rucksack item."));
+            }
+            if scheme.slot(*item).is_some() {
+                return Err(PrioritySchemeError("Each item can only appear once in a priority scheme."));
+            }
+            scheme.items.push(*item);
+            scheme.priorities.push(*priority);
+            let slot = scheme.items.len() as u8;
+            match u8::try_from(*item) {
+                Ok(byte) if byte.is_ascii() => scheme.ascii[byte as usize] = slot,
+                _ => {
+                    scheme.others.insert(*item, slot);
+                }
+            }
+        }
+        Ok(scheme)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn slot(&self, item: char) -> Option<usize> {
+        let slot = match u8::try_from(item) {
+            Ok(byte) if byte.is_ascii() => self.ascii[byte as usize],
+            _ => self.others.get(&item).copied().unwrap_or(0),
+        };
+        (slot != 0).then_some(slot as usize)
+    }
+
+    pub fn item(&self, slot: usize) -> char {
+        self.items[slot - 1]
+    }
+
+    pub fn slot_priority(&self, slot: usize) -> u32 {
+        self.priorities[slot - 1]
+    }
+
+    // None for items outside the scheme.
+    pub fn priority(&self, item: char) -> Option<u32> {
+        self.slot(item).map(|slot| self.slot_priority(slot))
+    }
+
+    pub fn item_set(&self, items: &str) -> Result<ItemSet, PrioritySchemeError> {
+        let mut set = ItemSet::new();
+        for item in items.chars() {
+            set.insert(self.slot(item).ok_or(PrioritySchemeError("Item isn't in the priority scheme."))?);
+        }
+        Ok(set)
+    }
+
+    // A set's items, in the scheme's order.
+    pub fn items<'a>(&'a self, set: &'a ItemSet) -> impl Iterator<Item = char> + 'a {
+        set.slots().map(|slot| self.item(slot))
+    }
+
+    pub fn priority_sum(&self, set: &ItemSet) -> u32 {
+        set.slots().map(|slot| self.slot_priority(slot)).sum()
+    }
+}
+
+custom_error!(PrioritySchemeError);
@@ -1,7 +1,7 @@
 use crate::util::{read_input_to_str, parse_lines_into, AOCSolution};
 use std::{str::FromStr};
 
-solution!(Day 4 => CampSectionRegistry);
+solution!(Year 2022 Day 4 => CampSectionRegistry);
 
 pub struct CampSectionRegistry {
     sections: Vec<CampSectionRecord>
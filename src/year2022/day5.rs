@@ -1,7 +1,7 @@
 use crate::util::{read_input_to_str, parse_lines_into, convert_str_to_sections, AOCSolution};
 use std::str::FromStr;
 
-solution!(Day 5 => CrateTowers);
+solution!(Year 2022 Day 5 => CrateTowers);
 
 pub struct CrateTowers {
     src: String,
@@ -21,7 +21,7 @@ impl AOCSolution for CrateTowers {
         let tops = self.get_top_of_stacks();
         let tops = tops.iter().map(|t| t.unwrap().0).collect::<String>();
 
-        format!("{}", tops)
+        tops
     }
 
     fn part_2(&mut self) -> String {
@@ -30,7 +30,7 @@ impl AOCSolution for CrateTowers {
         let tops = self.get_top_of_stacks();
         let tops = tops.iter().map(|t| t.unwrap().0).collect::<String>();
 
-        format!("{}", tops)
+        tops
     }
 }
 
@@ -44,7 +44,7 @@ impl CrateTowers {
         let sections = convert_str_to_sections(input_str, false)?;
         let crate_diagram = sections.first().expect("Should have two sections");
         let instructions = parse_lines_into::<CraneInstruction>(&sections[1])?;
-        let stacks = Self::parse_crate_diagram(&crate_diagram)?;
+        let stacks = Self::parse_crate_diagram(crate_diagram)?;
         Ok(Self {
             src: input_str.to_string(),
             stacks,
@@ -55,7 +55,7 @@ impl CrateTowers {
     pub fn reset(&mut self) {
         let sections = convert_str_to_sections(&self.src, false).expect("Worked on instantiation");
         let crate_diagram = sections.first().expect("Should have diagram");
-        let stacks = Self::parse_crate_diagram(&crate_diagram).expect("Worked on instantiation");
+        let stacks = Self::parse_crate_diagram(crate_diagram).expect("Worked on instantiation");
         self.stacks = stacks;
     }
 
@@ -64,13 +64,13 @@ impl CrateTowers {
         let lines = diagram.lines();
         // Every representative element is 3 chars with 1 char spacing between
         for line in lines {
-            let line_chars = line.chars().into_iter().collect::<Vec<char>>();
+            let line_chars = line.chars().collect::<Vec<char>>();
             let blocks = line_chars.chunks(4);
             for (idx, block) in blocks.enumerate() {
                 if result.len() <= idx {
                     result.push(CrateStack::new());
                 }
-                let cr_repr: String = block.into_iter().collect();
+                let cr_repr: String = block.iter().collect();
                 let cr_repr = cr_repr.trim();
                 if let Ok(cr) = cr_repr.parse::<Crate>() {
                     result[idx].load(cr);
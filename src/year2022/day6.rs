@@ -1,9 +1,15 @@
-use crate::util::{read_input_to_str, AOCSolution};
+use crate::util::{read_input_to_str, AOCSolution, PuzzleParams};
 
-solution!(Day 6 => CommSystem);
+solution!(Year 2022 Day 6 => CommSystem);
+
+params!(CommParams {
+    signal_marker_size: usize = 4 => 1..,
+    message_marker_size: usize = 14 => 1..,
+});
 
 pub struct CommSystem {
     stream: String,
+    params: CommParams,
 }
 
 impl AOCSolution for CommSystem {
@@ -21,6 +27,10 @@ impl AOCSolution for CommSystem {
     fn part_2(&mut self) -> String {
         format!("{}", self.scan_for_message_start())
     }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
 }
 
 impl CommSystem {
@@ -32,15 +42,16 @@ impl CommSystem {
     pub fn new_from_str(input_str: &str) -> Result<Self, Box<dyn std::error::Error>> {
         Ok(Self {
             stream: input_str.to_string(),
+            params: CommParams::default(),
         })
     }
 
     pub fn scan_for_signal_start(&self) -> usize {
-        self.find_first_unique_substream_of_size(4)
+        self.find_first_unique_substream_of_size(self.params.signal_marker_size)
     }
 
     pub fn scan_for_message_start(&self) -> usize {
-        self.find_first_unique_substream_of_size(14)
+        self.find_first_unique_substream_of_size(self.params.message_marker_size)
     }
 
     /*
@@ -70,7 +81,7 @@ impl CommSystem {
 struct Scanner {
     buf_size: usize,
     buffer: Vec<u8>,
-    counts: std::collections::HashMap<u8, u8>,
+    counts: std::collections::HashMap<u8, usize>,
     position: usize,
 }
 
@@ -162,4 +173,12 @@ mod test {
             test.execute();
         }
     }
+
+    #[test]
+    fn marker_sizes() {
+        let mut comms = CommSystem::new_from_str(&"a".repeat(300)).unwrap();
+        assert!(comms.params_mut().unwrap().set("signal_marker_size", "0").is_err());
+        comms.params_mut().unwrap().set("signal_marker_size", "300").unwrap();
+        assert_eq!(comms.scan_for_signal_start(), 0);
+    }
 }
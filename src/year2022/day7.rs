@@ -1,14 +1,23 @@
-use crate::util::{parse_lines_into, read_input_to_str, AOCSolution};
+use crate::util::{parse_lines_into, read_input_to_str, AOCSolution, PuzzleParams, SResult};
 use std::collections::HashMap;
 use std::str::FromStr;
 
-solution!(Day 7 => FileSystem);
+solution!(Year 2022 Day 7 => FileSystem);
+
+params!(FileSystemParams {
+    small_dir_limit: u32 = 100000,
+    total_space: u32 = 70000000,
+    required_space: u32 = 30000000,
+});
 
 #[derive(Debug)]
 pub struct FileSystem {
     root: Directory,
     path: Vec<String>,
     journal: Journal,
+    // Whether the journal has been replayed into `root`, which only happens once.
+    parsed: bool,
+    params: FileSystemParams,
 }
 
 impl AOCSolution for FileSystem {
@@ -21,18 +30,36 @@ impl AOCSolution for FileSystem {
 
     fn part_1(&mut self) -> String {
         self.parse_journal();
-        let sizes = self.get_dir_sizes_under_threshold(100000);
+        let sizes = self.get_dir_sizes_under_threshold(self.params.small_dir_limit);
         let total: u32 = sizes.iter().sum();
         format!("{}", total)
     }
 
     fn part_2(&mut self) -> String {
-        let unused_space = self.get_unused_space();
-        let desired_space: u32 = 30000000;
-        let threshold = desired_space - unused_space;
+        self.try_part_2().unwrap_or_else(|e| e.to_string())
+    }
+
+    // The disk sizes can be overridden, so they may not leave room for the files or need anything freeing.
+    fn try_part_2(&mut self) -> SResult<String> {
+        self.parse_journal();
+        let unused_space = self
+            .get_unused_space()
+            .ok_or(FileSystemError("The files take up more than the total space"))?;
+        let threshold = self
+            .params
+            .required_space
+            .checked_sub(unused_space)
+            .ok_or(FileSystemError("There's already enough unused space"))?;
         let sizes = self.get_dir_sizes_over_threshold(threshold);
-        let value = sizes.iter().min().unwrap();
-        format!("{}", value)
+        let value = sizes
+            .iter()
+            .min()
+            .ok_or(FileSystemError("No directory is big enough to free the required space"))?;
+        Ok(format!("{}", value))
+    }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
     }
 }
 
@@ -50,13 +77,16 @@ impl FileSystem {
             root,
             path: Vec::new(),
             journal: Journal { lines: entries },
+            parsed: false,
+            params: FileSystemParams::default(),
         })
     }
 
     fn parse_journal(&mut self) {
-        if self.root.files.len() != 0 && self.root.subdirs.len() != 0 {
+        if self.parsed {
             return;
         }
+        self.parsed = true;
         let lines = self.journal.lines.clone();
         for line in lines.iter().skip(1) {
             self.parse_line(line);
@@ -65,7 +95,7 @@ impl FileSystem {
     }
 
     fn parse_line(&mut self, line: &TerminalLine) {
-        if self.path.len() == 0 {
+        if self.path.is_empty() {
             self.handle_empty_path(line);
             return;
         }
@@ -84,7 +114,7 @@ impl FileSystem {
     }
     
     fn process_from_cwd(&mut self, line: &TerminalLine) {
-        if let None = self.get_cwd() { return; }
+        if self.get_cwd().is_none() { return; }
         let old_path = self.path.clone();
         let mut cwd = self.get_cwd().unwrap().clone();
         self.parse_line_from(&mut cwd, line);
@@ -92,7 +122,7 @@ impl FileSystem {
         *cwd_ref = cwd;
     }
     
-    fn get_dir_at_path(&mut self, path: &Vec<String>) -> Option<&mut Box<Directory>> {
+    fn get_dir_at_path(&mut self, path: &[String]) -> Option<&mut Box<Directory>> {
         let mut cwd = self.root.subdirs.get_mut(path.first().unwrap());
         for dirname in path.iter().skip(1) {
             let unwrapped_cwd = cwd.unwrap();
@@ -107,7 +137,7 @@ impl FileSystem {
                 self.parse_cmd(cmd);
             }
             TerminalLine::Output(out) => match out {
-                TerminalOutput::Dir(dirname) => cwd.append_dir(&dirname),
+                TerminalOutput::Dir(dirname) => cwd.append_dir(dirname),
                 TerminalOutput::File(f) => cwd.append_file(f.clone()),
             },
         }
@@ -119,9 +149,10 @@ impl FileSystem {
             TerminalCommand::CD(dirname) => {
                 if dirname == ".." {
                     self.path.pop();
-                    return;
+                } else {
+                    self.path.push(dirname.clone());
                 }
-                self.path.push(dirname.clone());
+                trace_event!(Debug, "cd", dir = dirname, path = self.path.join("/"));
             }
         }
     }
@@ -137,11 +168,10 @@ impl FileSystem {
             .collect()
     }
 
-    fn get_unused_space(&self) -> u32 {
-        let total_space: u32 = 70000000;
+    fn get_unused_space(&self) -> Option<u32> {
         let dir_sizes = self.get_dir_sizes();
         let used_space = dir_sizes.iter().max().unwrap();
-        total_space - used_space
+        self.params.total_space.checked_sub(*used_space)
     }
 
     fn get_dir_sizes_over_threshold(&self, threshold: u32) -> Vec<u32> {
@@ -185,8 +215,7 @@ impl Directory {
         let mut subsizes: Vec<u32> = self
             .subdirs
             .values()
-            .map(|d| d.get_subdir_sizes())
-            .flatten()
+            .flat_map(|d| d.get_subdir_sizes())
             .collect();
         subsizes.extend(this_size);
         subsizes
@@ -221,6 +250,7 @@ impl FromStr for TerminalLine {
 }
 
 custom_error!(TerminalLineParseErr);
+custom_error!(FileSystemError);
 
 impl From<TerminalCommandParseErr> for TerminalLineParseErr {
     fn from(e: TerminalCommandParseErr) -> Self {
@@ -323,4 +353,25 @@ mod test {
         assert_eq!(fs.part_1(), "95437");
         assert_eq!(fs.part_2(), "24933642");
     }
+
+    #[test]
+    fn parts_parse_once() {
+        let mut fs = FileSystem::new_from_str("$ cd /\n$ ls\n100 a\n200 b").expect("Failed to parse");
+        fs.params_mut().unwrap().set("total_space", "1000").unwrap();
+        fs.params_mut().unwrap().set("required_space", "900").unwrap();
+        assert_eq!(fs.part_1(), "300");
+        assert_eq!(fs.part_2(), "300");
+    }
+
+    #[test]
+    fn disk_size_overrides() {
+        let mut fs = FileSystem::new_from_str("$ cd /\n$ ls\n100 a\n200 b").expect("Failed to parse");
+        fs.params_mut().unwrap().set("total_space", "1").unwrap();
+        assert!(fs.try_part_2().is_err());
+        fs.params_mut().unwrap().set("total_space", "1000").unwrap();
+        fs.params_mut().unwrap().set("required_space", "100").unwrap();
+        assert!(fs.try_part_2().is_err());
+        fs.params_mut().unwrap().set("required_space", "2000").unwrap();
+        assert!(fs.try_part_2().is_err());
+    }
 }
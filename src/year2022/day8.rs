@@ -1,7 +1,7 @@
 use crate::util::{AOCSolution, read_input_to_str, str_to_grid_info, parse_each_char};
 use std::str::FromStr;
 
-solution!(Day 8 => Forest);
+solution!(Year 2022 Day 8 => Forest);
 
 pub struct Forest {
     trees: Vec<Tree>,
@@ -1,13 +1,18 @@
 use std::{str::FromStr, collections::HashSet};
 
-use crate::util::{AOCSolution, parse_lines_into, read_input_to_str};
+use crate::util::{AOCSolution, PuzzleParams, parse_lines_into, read_input_to_str};
 
-solution!(Day 9 => Simulator);
+solution!(Year 2022 Day 9 => Simulator);
+
+params!(SimulatorParams {
+    long_rope_tails: usize = 9,
+});
 
 pub struct Simulator {
     head: HeadPosition,
     tail: Vec<TailPosition>,
-    instructions: Vec<Instruction>
+    instructions: Vec<Instruction>,
+    params: SimulatorParams,
 }
 
 impl AOCSolution for Simulator {
@@ -24,10 +29,14 @@ impl AOCSolution for Simulator {
     
     fn part_2(&mut self) -> String {
         self.reset();
-        self.with_tails(9);
+        self.with_tails(self.params.long_rope_tails);
         self.run();
         format!("{}", self.get_unique_tail_positions())
     }
+
+    fn params_mut(&mut self) -> Option<&mut dyn PuzzleParams> {
+        Some(&mut self.params)
+    }
 }
 
 impl Simulator {
@@ -43,7 +52,8 @@ impl Simulator {
         Ok(Self {
             instructions,
             head,
-            tail
+            tail,
+            params: SimulatorParams::default(),
         })
     }
     
@@ -84,9 +94,9 @@ struct HeadPosition {
     position: (i32, i32)
 }
 
-impl Into<(i32, i32)> for HeadPosition {
-    fn into(self) -> (i32, i32) {
-        self.position
+impl From<HeadPosition> for (i32, i32) {
+    fn from(head: HeadPosition) -> Self {
+        head.position
     }
 }
 
@@ -142,9 +152,9 @@ impl TailPosition {
     }
 }
 
-impl Into<(i32, i32)> for TailPosition {
-    fn into(self) -> (i32, i32) {
-        self.position
+impl From<TailPosition> for (i32, i32) {
+    fn from(tail: TailPosition) -> Self {
+        tail.position
     }
 }
 
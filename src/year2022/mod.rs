@@ -0,0 +1,109 @@
+#[cfg(feature = "day1")]
+pub mod day1;
+#[cfg(feature = "day2")]
+pub mod day2;
+#[cfg(feature = "day3")]
+pub mod day3;
+#[cfg(feature = "day4")]
+pub mod day4;
+#[cfg(feature = "day5")]
+pub mod day5;
+#[cfg(feature = "day6")]
+pub mod day6;
+#[cfg(feature = "day7")]
+pub mod day7;
+#[cfg(feature = "day8")]
+pub mod day8;
+#[cfg(feature = "day9")]
+pub mod day9;
+#[cfg(feature = "day10")]
+pub mod day10;
+#[cfg(feature = "day11")]
+pub mod day11;
+#[cfg(feature = "day12")]
+pub mod day12;
+#[cfg(feature = "day13")]
+pub mod day13;
+#[cfg(feature = "day14")]
+pub mod day14;
+#[cfg(feature = "day15")]
+pub mod day15;
+#[cfg(feature = "day16")]
+pub mod day16;
+#[cfg(feature = "day17")]
+pub mod day17;
+#[cfg(feature = "day18")]
+pub mod day18;
+#[cfg(feature = "day19")]
+pub mod day19;
+#[cfg(feature = "day20")]
+pub mod day20;
+#[cfg(feature = "day21")]
+pub mod day21;
+#[cfg(feature = "day22")]
+pub mod day22;
+#[cfg(feature = "day23")]
+pub mod day23;
+#[cfg(feature = "day24")]
+pub mod day24;
+#[cfg(feature = "day25")]
+pub mod day25;
+
+use crate::util::RegisteredSolution;
+
+// Only days enabled through their cargo feature are compiled and registered.
+#[allow(unused_mut, clippy::vec_init_then_push)]
+pub fn registry() -> Vec<RegisteredSolution> {
+    let mut days: Vec<RegisteredSolution> = Vec::new();
+    #[cfg(feature = "day1")]
+    days.push(day1::registration());
+    #[cfg(feature = "day2")]
+    days.push(day2::registration());
+    #[cfg(feature = "day3")]
+    days.push(day3::registration());
+    #[cfg(feature = "day4")]
+    days.push(day4::registration());
+    #[cfg(feature = "day5")]
+    days.push(day5::registration());
+    #[cfg(feature = "day6")]
+    days.push(day6::registration());
+    #[cfg(feature = "day7")]
+    days.push(day7::registration());
+    #[cfg(feature = "day8")]
+    days.push(day8::registration());
+    #[cfg(feature = "day9")]
+    days.push(day9::registration());
+    #[cfg(feature = "day10")]
+    days.push(day10::registration());
+    #[cfg(feature = "day11")]
+    days.push(day11::registration());
+    #[cfg(feature = "day12")]
+    days.push(day12::registration());
+    #[cfg(feature = "day13")]
+    days.push(day13::registration());
+    #[cfg(feature = "day14")]
+    days.push(day14::registration());
+    #[cfg(feature = "day15")]
+    days.push(day15::registration());
+    #[cfg(feature = "day16")]
+    days.push(day16::registration());
+    #[cfg(feature = "day17")]
+    days.push(day17::registration());
+    #[cfg(feature = "day18")]
+    days.push(day18::registration());
+    #[cfg(feature = "day19")]
+    days.push(day19::registration());
+    #[cfg(feature = "day20")]
+    days.push(day20::registration());
+    #[cfg(feature = "day21")]
+    days.push(day21::registration());
+    #[cfg(feature = "day22")]
+    days.push(day22::registration());
+    #[cfg(feature = "day23")]
+    days.push(day23::registration());
+    #[cfg(feature = "day24")]
+    days.push(day24::registration());
+    #[cfg(feature = "day25")]
+    days.push(day25::registration());
+    days
+}
@@ -0,0 +1,136 @@
+"""Exercises every day through the C API with ctypes.
+
+Build the library first, then run from the repository root:
+
+    cargo build --release --lib
+    python3 -m unittest discover tests/ffi
+
+Set AOC_LIB to the path of the shared library to test a different build.
+"""
+
+import ctypes
+import os
+import sys
+import unittest
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parents[2]
+EXAMPLES = Path(__file__).resolve().parent / "examples"
+
+AOC_ERROR_INVALID_ARGUMENT = -1
+AOC_ERROR_SOLVE = -2
+
+# Days 1 to 15 run on the checked-in puzzle inputs, the rest on the examples from the puzzle text.
+# Day 15's example needs different parameters, which the C API doesn't expose.
+SCREEN = (
+    "\n"
+    "####.####..##..####.###..#..#.###..####.\n"
+    "#....#....#..#.#....#..#.#..#.#..#.#....\n"
+    "###..###..#....###..#..#.#..#.#..#.###..\n"
+    "#....#....#.##.#....###..#..#.###..#....\n"
+    "#....#....#..#.#....#.#..#..#.#.#..#....\n"
+    "####.#.....###.####.#..#..##..#..#.####.\n"
+)
+EXPECTED = {
+    1: ("74711", "209481"),
+    2: ("11767", "13886"),
+    3: ("7793", "2499"),
+    4: ("485", "857"),
+    5: ("ZRLJGSCTR", "PRTTGRFPB"),
+    6: ("1356", "2564"),
+    7: ("1444896", "404395"),
+    8: ("1700", "470596"),
+    9: ("5710", "2259"),
+    10: ("14760", SCREEN),
+    11: ("50830", "14399640002"),
+    12: ("447", "446"),
+    13: ("5503", "20952"),
+    14: ("892", "27155"),
+    15: ("4886370", "11374534948438"),
+    16: ("1651", "1707"),
+    17: ("3068", "1514285714288"),
+    18: ("64", "58"),
+    19: ("33", "3472"),
+    20: ("3", "1623178306"),
+    21: ("152", "301"),
+    22: ("6032", "5031"),
+    23: ("110", "20"),
+    24: ("18", "54"),
+    25: ("2=-1=0", "Merry Christmas!"),
+}
+
+
+def library_path():
+    if "AOC_LIB" in os.environ:
+        return Path(os.environ["AOC_LIB"])
+    name = {"win32": "aoc.dll", "darwin": "libaoc.dylib"}.get(sys.platform, "libaoc.so")
+    for profile in ("release", "debug"):
+        path = ROOT / "target" / profile / name
+        if path.exists():
+            return path
+    raise unittest.SkipTest("Shared library not built, run `cargo build --release --lib` first.")
+
+
+def load_library():
+    lib = ctypes.CDLL(str(library_path()))
+    lib.aoc_solve.argtypes = [
+        ctypes.c_uint32,
+        ctypes.c_uint32,
+        ctypes.c_char_p,
+        ctypes.c_size_t,
+        ctypes.c_char_p,
+        ctypes.c_size_t,
+    ]
+    lib.aoc_solve.restype = ctypes.c_int32
+    lib.aoc_days.argtypes = [ctypes.POINTER(ctypes.c_uint32), ctypes.c_size_t]
+    lib.aoc_days.restype = ctypes.c_size_t
+    return lib
+
+
+def input_for(day):
+    path = ROOT / "input" / "2022" / f"day{day}.txt"
+    if not path.exists():
+        path = EXAMPLES / f"day{day}.txt"
+    return path.read_bytes()
+
+
+class SolveTest(unittest.TestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls.lib = load_library()
+
+    def solve(self, day, part, data, out_len=64):
+        out = ctypes.create_string_buffer(out_len)
+        written = self.lib.aoc_solve(day, part, data, len(data), out, out_len)
+        if written >= out_len:
+            # Truncated, so retry with room for the whole answer.
+            return self.solve(day, part, data, written + 1)
+        return written, out.value.decode()
+
+    def days(self):
+        count = self.lib.aoc_days(None, 0)
+        days = (ctypes.c_uint32 * count)()
+        self.lib.aoc_days(days, count)
+        return list(days)
+
+    def test_every_day(self):
+        self.assertEqual(sorted(self.days()), sorted(EXPECTED))
+        for day in self.days():
+            data = input_for(day)
+            for part, expected in enumerate(EXPECTED[day], start=1):
+                with self.subTest(day=day, part=part):
+                    written, answer = self.solve(day, part, data)
+                    self.assertEqual(answer, expected)
+                    self.assertEqual(written, len(expected.encode()))
+
+    def test_errors(self):
+        data = b"1000\n2000"
+        written, message = self.solve(1, 3, data)
+        self.assertEqual(written, AOC_ERROR_SOLVE)
+        self.assertIn("part", message)
+        self.assertEqual(self.solve(99, 1, data)[0], AOC_ERROR_SOLVE)
+        self.assertEqual(self.solve(1, 1, b"\xff\xfe")[0], AOC_ERROR_INVALID_ARGUMENT)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,32 @@
+// The counting allocator's totals are process-wide, so it gets this binary to itself, run without the test
+// harness: nothing else allocates while a measurement is taken.
+// Only the counters are used here, not the runner's printing or its unit tests.
+#[allow(dead_code, unused_imports)]
+#[path = "../src/profiling.rs"]
+mod profiling;
+
+use profiling::{measure, CountingAllocator};
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn main() {
+    counts_allocations();
+    counts_only_growth_on_realloc();
+    println!("profiling: ok");
+}
+
+fn counts_allocations() {
+    let (v, m) = measure(|| vec![0u8; 4096]);
+    assert_eq!(v.len(), 4096);
+    assert_eq!(m.allocations, 1);
+    assert_eq!(m.bytes_allocated, 4096);
+    assert_eq!(m.peak_bytes, 4096);
+}
+
+fn counts_only_growth_on_realloc() {
+    let mut v = Vec::<u8>::with_capacity(1 << 20);
+    let (_, m) = measure(|| v.reserve_exact((1 << 20) + 4096));
+    assert_eq!(m.allocations, 0);
+    assert_eq!(m.bytes_allocated, 4096);
+}
@@ -0,0 +1,11 @@
+#![cfg(feature = "day10")]
+
+use aoc::snapshot::assert_snapshot;
+
+// The CRT screen as the runner prints it, which the unit test in day10.rs doesn't cover.
+#[test]
+fn day10_screen_through_solve() {
+    let input = include_str!("snapshots/2022_day10_program.txt");
+    let screen = aoc::solve(2022, 10, 2, input).expect("Failed to solve");
+    assert_snapshot("2022_day10_part_2", &screen);
+}
@@ -0,0 +1,18 @@
+#![cfg(target_arch = "wasm32")]
+
+use aoc::wasm::{days, solve};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn solves_given_input() {
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+    assert_eq!(solve(1, 1, input), "24000");
+    assert_eq!(solve(1, 2, input), "45000");
+}
+
+#[wasm_bindgen_test]
+fn reports_errors_as_text() {
+    assert!(solve(1, 3, "1000").starts_with("Error:"));
+    assert!(solve(99, 1, "1000").starts_with("Error:"));
+    assert!(days().contains(&1));
+}